@@ -0,0 +1,94 @@
+use core::iter::FusedIterator;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that removes consecutive duplicate elements from the wrapped iterator,
+/// and reports a size hint consistent with that behavior.
+///
+/// To detect duplicates, one element is buffered ahead of the wrapped iterator. As any number of
+/// elements may be removed, the reported [`Iterator::size_hint`] has a lower bound of at most 1,
+/// while the upper bound is the wrapped iterator's upper bound, including the buffered element.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut iter = [1, 1, 2, 2, 3].into_iter().dedup_hint();
+/// assert_eq!(iter.size_hint(), (1, Some(5)), "Any number of elements may be duplicates");
+///
+/// assert_eq!(iter.next(), Some(1), "Should skip consecutive duplicates");
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3], "Should skip consecutive duplicates");
+/// ```
+#[derive(Debug, Clone)]
+#[readonly::make]
+pub struct DedupHint<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The buffered next element, if any.
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator<Item: PartialEq>> DedupHint<I> {
+    /// Wraps `iterator` in a new [`DedupHint`].
+    ///
+    /// The first element of `iterator` is buffered immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::DedupHint;
+    /// let iter = DedupHint::new([1, 1, 2]);
+    /// assert_eq!(iter.size_hint(), (1, Some(3)));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>) -> Self {
+        let mut iterator = iterator.into_iter();
+        let last = iterator.next();
+        Self { iterator, last }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// Any buffered element is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::DedupHint;
+    /// let iter = DedupHint::new(1..5);
+    /// let mut inner: core::ops::Range<i32> = iter.into_inner();
+    /// assert_eq!(inner.next(), Some(2), "Buffered element is discarded");
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator<Item: PartialEq>> Iterator for DedupHint<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.last.take()?;
+        self.last = self.iterator.by_ref().find(|item| *item != last);
+        Some(last)
+    }
+
+    /// Returns `(1, upper + 1)` while an element is buffered, where `upper` is the wrapped
+    /// iterator's upper bound, or `(0, Some(0))` once exhausted.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.last {
+            Some(_) => (1, self.iterator.size_hint().1.and_then(|upper| upper.checked_add(1))),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+/// Once the buffered element is exhausted, the wrapped iterator is never polled again.
+impl<I: Iterator<Item: PartialEq>> FusedIterator for DedupHint<I> {}
@@ -0,0 +1,141 @@
+use core::iter::FusedIterator;
+use core::ops::Not;
+
+use fluent_result::bool::Then;
+
+use crate::{InvalidSizeHint, SizeHint};
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that reports [`SizeHint::UNIVERSAL`] until its first element is
+/// consumed, then reports a tracked size hint.
+///
+/// This models iterators whose size only becomes known after iteration has started, and is
+/// useful for testing consumers that query [`Iterator::size_hint`] once before iterating and
+/// cache the result. The tracked size hint is decremented for each element consumed, including
+/// the first. As the tracked size hint may be bounded, the wrapped iterator must be a
+/// [`FusedIterator`].
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::{SizeHint, SizeHinter};
+/// let mut iter = (1..5).delayed_hint(SizeHint::bounded(2, 6));
+/// assert_eq!(iter.size_hint(), (0, None), "Should be universal before the first element");
+///
+/// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+/// assert_eq!(iter.size_hint(), (1, Some(5)), "Should report the tracked size hint");
+/// ```
+#[derive(Debug, Clone)]
+#[readonly::make]
+pub struct DelayedHint<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The tracked size hint, reported once an element has been consumed.
+    pub hint: SizeHint,
+    /// Whether an element has been consumed.
+    pub started: bool,
+}
+
+impl<I: Iterator> DelayedHint<I> {
+    /// Wraps `iterator` in a new [`DelayedHint`] that reports `hint` once its first element is
+    /// consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `iterator`'s [`Iterator::size_hint`] is invalid
+    /// - `hint` does not overlap with `iterator`'s [`Iterator::size_hint`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{DelayedHint, SizeHint};
+    /// let iter = DelayedHint::new(1..5, SizeHint::exact(4));
+    /// assert_eq!(iter.size_hint(), (0, None), "Should be universal before the first element");
+    /// assert!(!iter.started);
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>, hint: SizeHint) -> Self
+    where
+        I: FusedIterator,
+    {
+        Self::try_new(iterator, hint).expect("Invalid size hint")
+    }
+
+    /// Tries to wrap `iterator` in a new [`DelayedHint`] that reports `hint` once its first
+    /// element is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `hint` does not overlap with `iterator`'s
+    /// [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{DelayedHint, InvalidSizeHint, SizeHint};
+    /// let err: InvalidSizeHint = DelayedHint::try_new(1..5, SizeHint::exact(10)).expect_err("hint should not overlap");
+    /// ```
+    #[inline]
+    pub fn try_new(iterator: impl IntoIterator<IntoIter = I>, hint: SizeHint) -> Result<Self, InvalidSizeHint>
+    where
+        I: FusedIterator,
+    {
+        let iterator = iterator.into_iter();
+        let wrapped: SizeHint = iterator.size_hint().try_into().expect("iterator's size hint should be valid");
+        SizeHint::overlaps(hint, wrapped).not().then_err(InvalidSizeHint)?;
+        Ok(Self { iterator, hint, started: false })
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{DelayedHint, SizeHint};
+    /// let iter = DelayedHint::new(1..5, SizeHint::exact(4));
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator> Iterator for DelayedHint<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.started = true;
+        self.hint = self.hint.decrement();
+        self.iterator.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.started {
+            true => self.hint.into(),
+            false => SizeHint::UNIVERSAL.into(),
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for DelayedHint<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.started = true;
+        self.hint = self.hint.decrement();
+        self.iterator.next_back()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for DelayedHint<I> {}
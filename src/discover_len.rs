@@ -0,0 +1,124 @@
+use core::iter::FusedIterator;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that counts the elements yielded by the wrapped iterator, and reports
+/// the discovered length once it is exhausted.
+///
+/// Only the number of elements yielded is observed. Once the wrapped iterator returns [`None`],
+/// [`DiscoverLen::discovered`] reports that count. This is useful for measuring the length of an
+/// iterator in a first pass, for use with [`ExactLen`] in a subsequent pass over a recreated
+/// iterator.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut odds = (1..=5).filter(|x| x % 2 == 1).discover_len();
+///
+/// assert_eq!(odds.next(), Some(1), "Underlying iterator is not changed");
+/// assert_eq!(odds.discovered(), None, "Length is not known until exhausted");
+///
+/// odds.by_ref().for_each(drop);
+/// assert_eq!(odds.discovered(), Some(3), "Length is known once exhausted");
+/// ```
+#[derive(Debug, Default, Clone)]
+#[readonly::make]
+pub struct DiscoverLen<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The number of elements yielded so far.
+    pub count: usize,
+    /// Whether the underlying iterator has returned [`None`].
+    pub exhausted: bool,
+}
+
+impl<I: Iterator> DiscoverLen<I> {
+    /// Wraps `iterator` in a new [`DiscoverLen`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::DiscoverLen;
+    /// let iter = DiscoverLen::new(1..5);
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Size hint is not changed");
+    /// assert_eq!(iter.discovered(), None, "Length is not known until exhausted");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>) -> Self {
+        Self { iterator: iterator.into_iter(), count: 0, exhausted: false }
+    }
+
+    /// Returns the number of elements yielded, if the wrapped iterator has been exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::DiscoverLen;
+    /// let mut iter = DiscoverLen::new(1..3);
+    /// assert_eq!(iter.discovered(), None, "Length is not known until exhausted");
+    ///
+    /// iter.by_ref().for_each(drop);
+    /// assert_eq!(iter.discovered(), Some(2), "Length is known once exhausted");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn discovered(&self) -> Option<usize> {
+        match self.exhausted {
+            true => Some(self.count),
+            false => None,
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::DiscoverLen;
+    /// let iter = DiscoverLen::new(1..5);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+
+    /// Records the result of advancing the wrapped iterator.
+    #[inline]
+    const fn record(&mut self, item: Option<I::Item>) -> Option<I::Item> {
+        match item {
+            Some(_) => self.count += 1,
+            None => self.exhausted = true,
+        }
+        item
+    }
+}
+
+impl<I: Iterator> Iterator for DiscoverLen<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next();
+        self.record(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for DiscoverLen<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next_back();
+        self.record(item)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for DiscoverLen<I> {}
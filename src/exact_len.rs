@@ -1,13 +1,15 @@
 use core::{
+    fmt::{self, Debug, Formatter},
     iter::FusedIterator,
     ops::{Not, RangeBounds},
+    slice,
 };
 
 use fluent_result::bool::Then;
 
 #[cfg(doc)]
 use crate::*;
-use crate::{InvalidSizeHint, SizeHint};
+use crate::{HintSize, InvalidSizeHint, SizeHint};
 
 /// A [`FusedIterator`] adaptor that provides an exact length via [`ExactSizeIterator`].
 ///
@@ -31,6 +33,11 @@ use crate::{InvalidSizeHint, SizeHint};
 /// provided length is accurate. Inaccurate values may cause incorrect behavior or panics in
 /// code that relies on these values.
 ///
+/// In debug builds, iterating an `ExactLen` whose length is found to be inaccurate panics.
+///
+/// Two `ExactLen`s are equal, and hash equally, if both their underlying iterators and their
+/// remaining lengths are equal.
+///
 /// # Examples
 ///
 /// ```rust
@@ -49,7 +56,7 @@ use crate::{InvalidSizeHint, SizeHint};
 /// assert_eq!(three_odds.len(), 1, "len should match the remaining length");
 /// assert_eq!(three_odds.size_hint(), (1, Some(1)), "size_hint should match len");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 #[readonly::make]
 pub struct ExactLen<I: FusedIterator> {
     /// The underlying iterator.
@@ -106,6 +113,42 @@ impl<I: FusedIterator> ExactLen<I> {
         Ok(Self { iterator, len })
     }
 
+    /// Skips the next `k` elements, returning the adaptor with its length reduced accordingly.
+    ///
+    /// Unlike [`Iterator::skip`], this advances the wrapped iterator via [`Iterator::nth`],
+    /// allowing it to skip elements efficiently, and preserves [`ExactSizeIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::ExactLen;
+    /// let mut iter = ExactLen::new(1..6, 5).skip_exact(2);
+    /// assert_eq!(iter.len(), 3, "len should be reduced by the skipped count");
+    /// assert_eq!(iter.next(), Some(3), "should skip the first two elements");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn skip_exact(mut self, k: usize) -> Self {
+        if let Some(n) = k.checked_sub(1) {
+            self.iterator.nth(n);
+            self.len = self.len.saturating_sub(k);
+        }
+        self
+    }
+
+    /// Updates `len` after advancing the wrapped iterator from either end past `n` elements,
+    /// yielding `item`.
+    ///
+    /// In debug builds, asserts that the wrapped iterator yields an element if and only if `len`
+    /// is greater than `n`, that is, that the provided `len` was accurate.
+    #[inline]
+    #[track_caller]
+    fn advance(&mut self, n: usize, item: Option<I::Item>) -> Option<I::Item> {
+        debug_assert!(item.is_some() == (n < self.len), "len should match the number of elements yielded");
+        self.len = self.len.saturating_sub(n.saturating_add(1));
+        item
+    }
+
     /// Consumes the adaptor and returns the underlying iterator.
     ///
     /// # Examples
@@ -121,6 +164,130 @@ impl<I: FusedIterator> ExactLen<I> {
     pub fn into_inner(self) -> I {
         self.iterator
     }
+
+    /// Consumes the adaptor and returns its current length, discarding the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let mut iter = (1..5).exact_len(4);
+    /// iter.next();
+    /// assert_eq!(iter.into_len(), 3, "Should be the remaining length");
+    /// ```
+    #[inline]
+    pub fn into_len(self) -> usize {
+        self.len
+    }
+
+    /// Relaxes this adaptor into a [`HintSize`] whose size hint is this adaptor's length, widened by
+    /// `slack` in both directions.
+    ///
+    /// The lower bound saturates at 0, and the upper bound saturates at `usize::MAX`. This is
+    /// useful when the length turns out to be only approximate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the relaxed size hint does not overlap with the wrapped iterator's
+    /// [`Iterator::size_hint`]. That is, if the relaxed lower bound is greater than the wrapped
+    /// upper bound, or the relaxed upper bound is less than the wrapped lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::ExactLen;
+    /// let mut iter = ExactLen::new(1..5, 4).relax(1);
+    /// assert_eq!(iter.size_hint(), (3, Some(5)), "Should widen the length by the slack");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (2, Some(4)), "Should reflect new state");
+    /// ```
+    #[inline]
+    pub fn relax(self, slack: usize) -> HintSize<I> {
+        let hint = SizeHint::bounded(self.len.saturating_sub(slack), self.len.saturating_add(slack));
+        HintSize::try_new_impl(self.iterator, hint).expect("Invalid size hint")
+    }
+
+    /// Converts this adaptor into a `rayon` [`IndexedParallelIterator`] over its elements.
+    ///
+    /// The elements are first collected sequentially into a [`Vec`](alloc::vec::Vec), using the
+    /// exact length for a single allocation, which is then iterated in parallel. This collection
+    /// has a cost proportional to the number of elements, so it is only worthwhile when the work
+    /// done per element in parallel is significant.
+    ///
+    /// [`IndexedParallelIterator`]: rayon::iter::IndexedParallelIterator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    ///
+    /// let iter = (1..=5).filter(|x| x % 2 == 1).exact_len(3).into_par();
+    /// assert_eq!(iter.len(), 3, "Should have the exact length");
+    /// assert_eq!(iter.sum::<i32>(), 9, "Should yield the same elements");
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn into_par(self) -> impl rayon::iter::IndexedParallelIterator<Item = I::Item>
+    where
+        I::Item: Send,
+    {
+        use rayon::iter::IntoParallelIterator;
+        self.collect::<alloc::vec::Vec<_>>().into_par_iter()
+    }
+
+    /// Boxes this adaptor as a type erased [`Iterator`].
+    ///
+    /// The boxed iterator still reports this adaptor's size hint. This is useful for storing
+    /// heterogeneous hinted iterators together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let iters: Vec<Box<dyn Iterator<Item = i32>>> = vec![(1..5).exact_len(4).boxed(), (1..3).exact_len(2).boxed()];
+    /// assert_eq!(iters[0].size_hint(), (4, Some(4)), "Boxed iterator should report the exact size hint");
+    /// assert_eq!(iters[1].size_hint(), (2, Some(2)), "Boxed iterator should report the exact size hint");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn boxed<'a>(self) -> alloc::boxed::Box<dyn Iterator<Item = I::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+}
+
+impl<T> ExactLen<slice::Iter<'_, T>> {
+    /// Splits this slice-backed adaptor into two adaptors, over the first `mid` remaining elements
+    /// and the rest respectively, each with an exact length.
+    ///
+    /// This is useful for divide-and-conquer consumers, as splitting the underlying slice is cheap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let (left, right) = [1, 2, 3, 4, 5].iter().exact_len(5).split_at(2);
+    /// assert_eq!(left.len(), 2, "Left half should have length mid");
+    /// assert_eq!(right.len(), 3, "Right half should have the remaining length");
+    /// assert_eq!(left.collect::<Vec<_>>(), vec![&1, &2], "Left half should yield the first elements");
+    /// assert_eq!(right.collect::<Vec<_>>(), vec![&3, &4, &5], "Right half should yield the rest");
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len, "mid should not be greater than len");
+        let (left, right) = self.iterator.as_slice().split_at(mid);
+        (Self { iterator: left.iter(), len: mid }, Self { iterator: right.iter(), len: self.len - mid })
+    }
 }
 
 impl<I: FusedIterator> Iterator for ExactLen<I> {
@@ -128,8 +295,14 @@ impl<I: FusedIterator> Iterator for ExactLen<I> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.len = self.len.saturating_sub(1);
-        self.iterator.next()
+        let item = self.iterator.next();
+        self.advance(0, item)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iterator.nth(n);
+        self.advance(n, item)
     }
 
     #[inline]
@@ -148,9 +321,30 @@ impl<I: FusedIterator> ExactSizeIterator for ExactLen<I> {
 impl<I: DoubleEndedIterator + FusedIterator> DoubleEndedIterator for ExactLen<I> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.len = self.len.saturating_sub(1);
-        self.iterator.next_back()
+        let item = self.iterator.next_back();
+        self.advance(0, item)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iterator.nth_back(n);
+        self.advance(n, item)
     }
 }
 
 impl<I: FusedIterator> FusedIterator for ExactLen<I> {}
+
+/// Compares the current size hint of this adaptor against a raw size hint tuple.
+impl<I: FusedIterator> PartialEq<(usize, Option<usize>)> for ExactLen<I> {
+    #[inline]
+    fn eq(&self, other: &(usize, Option<usize>)) -> bool {
+        SizeHint::exact(self.len) == *other
+    }
+}
+
+/// Formats the current length first, followed by the underlying iterator.
+impl<I: FusedIterator + Debug> Debug for ExactLen<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExactLen").field("len", &self.len).field("iterator", &self.iterator).finish()
+    }
+}
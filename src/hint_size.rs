@@ -1,9 +1,13 @@
-use core::{iter::FusedIterator, ops::Not};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::{Cycle, FusedIterator, Peekable, Take},
+    ops::Not,
+};
 
 use fluent_result::bool::Then;
 
-use crate::InvalidSizeHint;
 use crate::size_hint::SizeHint;
+use crate::{InvalidSizeHint, PreferInner};
 
 #[cfg(doc)]
 use crate::*;
@@ -18,14 +22,21 @@ use crate::*;
 ///
 /// Note this type is readonly. The field values may be read, but not modified.
 ///
+/// Two `HintSize`s are equal, and hash equally, if both their underlying iterators and their
+/// current size hints are equal.
+///
 /// # Fused iterator requirement
 ///
 /// [`HintSize`]s with an bounded size hint (those created by [`HintSize::new`] or [`HintSize::try_new`])
 /// are required to wrap a [`FusedIterator`], because after the iterator completes (returns [`None`]),
 /// this adaptor could no longer guarantee a correct bound upper value if iteration were to resume.
+/// For example, once the upper bound has been decremented to 0, an unfused iterator that resumes
+/// would yield elements that the size hint claims do not exist.
 ///
-/// Consider using an unbounded wrapper ([`HintSize::hide`] or [`HintSize::min`]) if the iterator is
-/// unfused.
+/// Unbounded size hints make no claim about how many elements may follow, and so remain correct
+/// for unfused iterators. Consider using an unbounded wrapper ([`HintSize::hide`] or
+/// [`HintSize::min`]) if the iterator is unfused. [`HintSize::wrap_dyn`] is the only exception,
+/// as a type erased iterator cannot be required to be fused.
 ///
 /// # Safety
 ///
@@ -45,7 +56,7 @@ use crate::*;
 /// incorrect behavior or panics in code that relies on these values.
 ///
 /// If constructed with values valid for the wrapped iterator, the returned size hint will always
-/// be valid. However, if the iterator is fused, the bounds of a bounded `HintSize` are not
+/// be valid. However, even if the iterator is fused, the bounds of a bounded `HintSize` are not
 /// guaranteed to converge at zero when iteration completes, and may change if a completed iterator
 /// is polled again.
 ///
@@ -73,7 +84,7 @@ use crate::*;
 /// assert_eq!(iter.next_back(), Some(4), "Underlying iterator is not changed");
 /// assert_eq!(iter.size_hint(), (1, Some(4)), "should reflect the new state");
 /// ```
-#[derive(Debug, Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 #[readonly::make]
 pub struct HintSize<I: Iterator> {
     /// The underlying iterator.
@@ -94,12 +105,27 @@ impl<I: Iterator> HintSize<I> {
     /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid
     #[inline]
     #[track_caller]
-    fn try_new_impl(iterator: I, hint: SizeHint) -> Result<Self, InvalidSizeHint> {
-        let wrapped: SizeHint = iterator.size_hint().try_into().expect("iterator's size hint should be valid");
-        SizeHint::overlaps(hint, wrapped).not().then_err(InvalidSizeHint)?;
+    pub(crate) fn try_new_impl(iterator: I, hint: SizeHint) -> Result<Self, InvalidSizeHint> {
+        Self::check_hint(&iterator, hint)?;
         Ok(Self { iterator, hint })
     }
 
+    /// Checks that `hint` overlaps with `iterator`'s current size hint.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the hint does not overlap with the `iterator`'s size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid
+    #[inline]
+    #[track_caller]
+    fn check_hint(iterator: &I, hint: SizeHint) -> Result<(), InvalidSizeHint> {
+        let wrapped: SizeHint = iterator.size_hint().try_into().expect("iterator's size hint should be valid");
+        SizeHint::overlaps(hint, wrapped).not().then_err(InvalidSizeHint)
+    }
+
     /// Wraps `iterator` in a new [`HintSize`] with an initial bounded size hint of
     /// `(lower, Some(upper))`.
     ///
@@ -213,6 +239,61 @@ impl<I: Iterator> HintSize<I> {
         Self::try_new_impl(iterator.into_iter(), SizeHint::unbounded(lower))
     }
 
+    /// Wraps `iterator` in a new [`HintSize`] with a size hint based on `upper` only, that is,
+    /// `(0, Some(upper))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `iterator`'s [`Iterator::size_hint`] is invalid
+    /// - `upper` is less than the wrapped iterator's lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::HintSize;
+    /// let mut iter = HintSize::max(1..5, 6);
+    /// assert_eq!(iter.size_hint(), (0, Some(6)), "Initial size hint reflects upper");
+    /// ```
+    #[inline]
+    pub fn max(iterator: impl IntoIterator<IntoIter = I>, upper: usize) -> Self
+    where
+        I: FusedIterator,
+    {
+        Self::try_max(iterator, upper).expect("Invalid size hint")
+    }
+
+    /// Tries to wrap `iterator` in a new [`HintSize`] with a size hint based on `upper` only,
+    /// that is, `(0, Some(upper))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `upper` is less than the wrapped iterator's lower bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{HintSize, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = HintSize::try_max(1..5, 6)?;
+    /// assert_eq!(iter.size_hint(), (0, Some(6)), "Initial size hint reflects upper");
+    ///
+    /// let err: InvalidSizeHint = HintSize::try_max(1..5, 3).expect_err("upper bound is < wrapped iterator's lower bound");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_max(iterator: impl IntoIterator<IntoIter = I>, upper: usize) -> Result<Self, InvalidSizeHint>
+    where
+        I: FusedIterator,
+    {
+        Self::try_new_impl(iterator.into_iter(), SizeHint::at_most(upper))
+    }
+
     /// Wraps `iterator` with a new [`Iterator::size_hint`] implementation with a universal size hint.
     ///
     /// This implementation, and the size hint it returns, is always correct, and never changes.
@@ -247,6 +328,335 @@ impl<I: Iterator> HintSize<I> {
     pub fn into_inner(self) -> I {
         self.iterator
     }
+
+    /// Consumes the adaptor and returns its current size hint, discarding the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, SizeHinter};
+    /// let mut iter = (1..5).hint_size(2, 6);
+    /// iter.next();
+    /// assert_eq!(iter.into_hint(), SizeHint::bounded(1, 5), "Should be the tracked size hint");
+    /// ```
+    #[inline]
+    pub fn into_hint(self) -> SizeHint {
+        self.hint
+    }
+
+    /// Replaces the current size hint with `hint`, if it is valid for the underlying iterator.
+    ///
+    /// This is useful when a better bound is learned partway through iteration. `hint` is
+    /// validated against the underlying iterator's current [`Iterator::size_hint`], as on
+    /// construction. If it is invalid, the current size hint is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `hint` does not overlap with the underlying iterator's
+    /// current [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying iterator's [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{HintSize, SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let mut iter = HintSize::new(1..5, 0, 10);
+    /// iter.next();
+    ///
+    /// iter.set_hint(SizeHint::exact(3))?;
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should reflect the new size hint");
+    ///
+    /// let err: InvalidSizeHint = iter.set_hint(SizeHint::exact(5)).expect_err("hint should not overlap");
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should keep the previous size hint");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn set_hint(&mut self, hint: SizeHint) -> Result<(), InvalidSizeHint>
+    where
+        I: FusedIterator,
+    {
+        Self::check_hint(&self.iterator, hint)?;
+        self.hint = hint;
+        Ok(())
+    }
+
+    /// Returns this adaptor with its size hint replaced by `hint`, if it is valid for the
+    /// underlying iterator.
+    ///
+    /// See [`Self::set_hint`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `hint` does not overlap with the underlying iterator's
+    /// current [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying iterator's [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{HintSize, SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = HintSize::new(1..5, 0, 10).with_hint(SizeHint::bounded(2, 6))?;
+    /// assert_eq!(iter.size_hint(), (2, Some(6)), "Should reflect the new size hint");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_hint(mut self, hint: SizeHint) -> Result<Self, InvalidSizeHint>
+    where
+        I: FusedIterator,
+    {
+        self.set_hint(hint)?;
+        Ok(self)
+    }
+
+    /// Returns this adaptor with its size hint replaced by the result of applying `f` to the
+    /// current size hint, if it is valid for the underlying iterator.
+    ///
+    /// This allows building up a size hint through a sequence of transformations without
+    /// reconstructing the adaptor. See [`Self::set_hint`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if the new size hint does not overlap with the underlying
+    /// iterator's current [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying iterator's [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{HintSize, SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = HintSize::new(1..5, 0, 10)
+    ///     .map_hint(|hint| hint.scale(2))?
+    ///     .map_hint(|hint| SizeHint::at_most(hint.upper.unwrap_or(usize::MAX).min(6)))?;
+    /// assert_eq!(iter.size_hint(), (0, Some(6)), "Should reflect the transformed size hint");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn map_hint<F: FnOnce(SizeHint) -> SizeHint>(self, f: F) -> Result<Self, InvalidSizeHint>
+    where
+        I: FusedIterator,
+    {
+        let hint = f(self.hint);
+        self.with_hint(hint)
+    }
+
+    /// Converts this adaptor into a [`PreferInner`] that tracks this adaptor's size hint, but
+    /// reports the wrapped iterator's size hint whenever it is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let iter = (1..).take(5).hint_size(2, 8).prefer_inner_when_exact();
+    /// assert_eq!(iter.hint, (2, Some(8)), "Should track this adaptor's size hint");
+    /// assert_eq!(iter.size_hint(), (5, Some(5)), "Should prefer the inner exact size hint");
+    /// ```
+    #[inline]
+    pub fn prefer_inner_when_exact(self) -> PreferInner<I> {
+        PreferInner::with_hint(self.iterator, self.hint)
+    }
+
+    /// Creates a [`HintSize`] that yields at most the first `n` elements of this adaptor.
+    ///
+    /// This shadows [`Iterator::take`], so that the adaptor type stays uniform across a pipeline.
+    /// The size hint of the new adaptor is this adaptor's size hint with both bounds capped at `n`.
+    /// As this size hint is bounded, the wrapped iterator must be a [`FusedIterator`]. Call
+    /// [`Iterator::take`] explicitly to take from an adaptor wrapping an unfused iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let mut iter = (1..10).hint_min(5).take(3);
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should cap the size hint at n");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (2, Some(2)), "Should reflect new state");
+    /// ```
+    #[inline]
+    pub fn take(self, n: usize) -> HintSize<Take<I>>
+    where
+        I: FusedIterator,
+    {
+        let upper = match self.hint.upper {
+            Some(upper) if upper < n => upper,
+            _ => n,
+        };
+        let hint = SizeHint::bounded(self.hint.lower.min(n), upper);
+        HintSize { iterator: self.iterator.take(n), hint }
+    }
+
+    /// Creates a [`HintSize`] that repeats the wrapped iterator endlessly, but yields only the
+    /// first `total` elements, with an exact size hint of `total`.
+    ///
+    /// Unlike [`Iterator::cycle`], this gives a well-defined, correctly hinted cyclic iterator.
+    /// This adaptor's size hint is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` is not 0, and the wrapped iterator is not known to yield at least one
+    /// element, that is, its [`Iterator::size_hint`] lower bound is 0. Otherwise, an iterator that
+    /// turns out to be empty would yield no elements, contradicting the size hint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let mut iter = (1..3).hide_size().cycle_capped(5);
+    /// assert_eq!(iter.size_hint(), (5, Some(5)), "Should be exactly the cap");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Should reflect new state");
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 1, 2, 1], "Should cycle the wrapped iterator");
+    /// ```
+    #[inline]
+    pub fn cycle_capped(self, total: usize) -> HintSize<Take<Cycle<I>>>
+    where
+        I: Clone + FusedIterator,
+    {
+        assert!(total == 0 || self.iterator.size_hint().0 != 0, "wrapped iterator should be known to be non-empty");
+        HintSize::try_new_impl(self.iterator.cycle().take(total), SizeHint::exact(total)).expect("Invalid size hint")
+    }
+
+    /// Boxes this adaptor as a type erased [`Iterator`].
+    ///
+    /// The boxed iterator still reports this adaptor's size hint. This is useful for storing
+    /// heterogeneous hinted iterators together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let iters: Vec<Box<dyn Iterator<Item = i32>>> = vec![(1..5).hint_min(2).boxed(), (1..3).hide_size().boxed()];
+    /// assert_eq!(iters[0].size_hint(), (2, None), "Boxed iterator should report the custom size hint");
+    /// assert_eq!(iters[1].size_hint(), (0, None), "Boxed iterator should report the custom size hint");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn boxed<'a>(self) -> alloc::boxed::Box<dyn Iterator<Item = I::Item> + 'a>
+    where
+        Self: 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+}
+
+impl<I: Iterator> HintSize<Peekable<I>> {
+    /// Consumes and returns the next element if `pred` returns `true` for it, decrementing the size
+    /// hint. Otherwise, returns [`None`] and leaves the iterator and size hint unchanged.
+    ///
+    /// See [`Peekable::next_if`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHinter;
+    /// let mut iter = (1..5).peekable().hint_size(3, 5);
+    ///
+    /// assert_eq!(iter.next_if(|&x| x == 1), Some(1), "Should consume a matching element");
+    /// assert_eq!(iter.size_hint(), (2, Some(4)), "Should decrement the size hint");
+    ///
+    /// assert_eq!(iter.next_if(|&x| x == 5), None, "Should not consume a non-matching element");
+    /// assert_eq!(iter.size_hint(), (2, Some(4)), "Should not change the size hint");
+    /// assert_eq!(iter.next(), Some(2), "Non-matching element should remain");
+    /// ```
+    #[inline]
+    pub fn next_if(&mut self, pred: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.iterator.peek() {
+            Some(item) if pred(item) => self.next(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, T> HintSize<&'a mut dyn Iterator<Item = T>> {
+    /// Wraps the type erased `iterator` in a new [`HintSize`] with an initial bounded size hint of
+    /// `(lower, Some(upper))`.
+    ///
+    /// Since a type erased iterator cannot be required to be a [`FusedIterator`], it is the
+    /// caller's responsibility to ensure that `iterator` is not polled again after completing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `iterator`'s [`Iterator::size_hint`] is invalid
+    /// - `lower > upper`
+    /// - `upper` is less than the wrapped iterator's lower bound
+    /// - `lower` is greater than the wrapped iterator's upper bound (if present)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::HintSize;
+    /// let mut range = 1..5;
+    /// let iter: &mut dyn Iterator<Item = i32> = &mut range;
+    /// let mut iter = HintSize::wrap_dyn(iter, 2, 6);
+    ///
+    /// assert_eq!(iter.size_hint(), (2, Some(6)), "should match the provided size hint");
+    /// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+    /// assert_eq!(iter.size_hint(), (1, Some(5)), "should reflect the new state");
+    /// ```
+    #[inline]
+    pub fn wrap_dyn(iterator: &'a mut dyn Iterator<Item = T>, lower: usize, upper: usize) -> Self {
+        Self::try_wrap_dyn(iterator, lower, upper).expect("Invalid size hint")
+    }
+
+    /// Tries to wrap the type erased `iterator` in a new [`HintSize`] with an initial bounded size
+    /// hint of `(lower, Some(upper))`.
+    ///
+    /// See [`HintSize::wrap_dyn`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if:
+    /// - `lower > upper`
+    /// - `upper` is less than the wrapped iterator's lower bound
+    /// - `lower` is greater than the wrapped iterator's upper bound (if present)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{HintSize, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let mut range = 1..5;
+    /// let iter = HintSize::try_wrap_dyn(&mut range, 2, 6)?;
+    /// assert_eq!(iter.size_hint(), (2, Some(6)), "should match the provided size hint");
+    ///
+    /// let result = HintSize::try_wrap_dyn(&mut range, 6, 10);
+    /// assert!(result.is_err(), "hint lower bound is > iterator's upper bound");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_wrap_dyn(
+        iterator: &'a mut dyn Iterator<Item = T>,
+        lower: usize,
+        upper: usize,
+    ) -> Result<Self, InvalidSizeHint> {
+        let hint = SizeHint::try_bounded(lower, upper)?;
+        Self::try_new_impl(iterator, hint)
+    }
 }
 
 impl<I: Iterator> Iterator for HintSize<I> {
@@ -262,6 +672,12 @@ impl<I: Iterator> Iterator for HintSize<I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.hint.into()
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.hint = self.hint.saturating_sub(n.saturating_add(1));
+        self.iterator.nth(n)
+    }
 }
 
 impl<I: DoubleEndedIterator> DoubleEndedIterator for HintSize<I> {
@@ -270,6 +686,30 @@ impl<I: DoubleEndedIterator> DoubleEndedIterator for HintSize<I> {
         self.hint = self.hint.decrement();
         self.iterator.next_back()
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.hint = self.hint.saturating_sub(n.saturating_add(1));
+        self.iterator.nth_back(n)
+    }
 }
 
 impl<I: Iterator + FusedIterator> FusedIterator for HintSize<I> {}
+
+/// Compares the current size hint of this adaptor against a raw size hint tuple.
+impl<I: Iterator> PartialEq<(usize, Option<usize>)> for HintSize<I> {
+    #[inline]
+    fn eq(&self, other: &(usize, Option<usize>)) -> bool {
+        self.hint == *other
+    }
+}
+
+/// Formats the current size hint first, in range notation, followed by the underlying iterator.
+impl<I: Iterator + Debug> Debug for HintSize<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HintSize")
+            .field("hint", &format_args!("{}", self.hint))
+            .field("iterator", &self.iterator)
+            .finish()
+    }
+}
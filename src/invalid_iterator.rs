@@ -70,3 +70,88 @@ impl<T> ExactSizeIterator for InvalidIterator<T> {
         unimplemented!("InvalidIterator does not have a valid len");
     }
 }
+
+/// A [`Iterator`] that reports an invalid size hint of `(LOWER, Some(UPPER))`, with the hint
+/// specified by const generics.
+///
+/// This behaves identically to [`InvalidIterator`], except that each distinct invalid hint is a
+/// distinct type. This is useful for testing trait implementations keyed on the iterator type.
+/// For the common case, prefer [`InvalidIterator`].
+///
+/// It is a compile time error to construct a [`ConstInvalidIterator`] where `LOWER <= UPPER`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::ConstInvalidIterator;
+/// let iter = ConstInvalidIterator::<(), 7, 3>::new();
+/// assert_eq!(iter.size_hint(), (7, Some(3)), "Size hint should match the const generics");
+/// ```
+///
+/// A valid size hint fails to compile, including when constructed through [`Default`].
+///
+/// ```rust,compile_fail
+/// # use size_hinter::ConstInvalidIterator;
+/// let iter = ConstInvalidIterator::<(), 1, 5>::new();
+/// ```
+///
+/// ```rust,compile_fail
+/// # use size_hinter::ConstInvalidIterator;
+/// let iter = ConstInvalidIterator::<(), 1, 5>::default();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConstInvalidIterator<T = (), const LOWER: usize = 10, const UPPER: usize = 5>(PhantomData<T>);
+
+impl<T, const LOWER: usize, const UPPER: usize> ConstInvalidIterator<T, LOWER, UPPER> {
+    /// A constant instance of `ConstInvalidIterator`.
+    pub const DEFAULT: Self = Self::new();
+
+    /// The invalid size hint this iterator returns.
+    pub const INVALID_SIZE_HINT: (usize, Option<usize>) = (LOWER, Some(UPPER));
+
+    /// Creates a new `ConstInvalidIterator`.
+    ///
+    /// Fails to compile if `LOWER <= UPPER`.
+    #[must_use]
+    pub const fn new() -> Self {
+        const { assert!(LOWER > UPPER, "LOWER should be greater than UPPER") };
+        Self(PhantomData)
+    }
+}
+
+/// Creates a new `ConstInvalidIterator`, failing to compile if `LOWER <= UPPER`.
+impl<T, const LOWER: usize, const UPPER: usize> Default for ConstInvalidIterator<T, LOWER, UPPER> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const LOWER: usize, const UPPER: usize> Iterator for ConstInvalidIterator<T, LOWER, UPPER> {
+    type Item = T;
+
+    /// Always panics.
+    fn next(&mut self) -> Option<Self::Item> {
+        unimplemented!("ConstInvalidIterator is not iteratable");
+    }
+
+    /// Always returns an invalid size hint of `(LOWER, Some(UPPER))`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Self::INVALID_SIZE_HINT
+    }
+}
+
+impl<T, const LOWER: usize, const UPPER: usize> DoubleEndedIterator for ConstInvalidIterator<T, LOWER, UPPER> {
+    /// Always panics.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        unimplemented!("ConstInvalidIterator is not iteratable");
+    }
+}
+
+impl<T, const LOWER: usize, const UPPER: usize> FusedIterator for ConstInvalidIterator<T, LOWER, UPPER> {}
+
+impl<T, const LOWER: usize, const UPPER: usize> ExactSizeIterator for ConstInvalidIterator<T, LOWER, UPPER> {
+    /// Always panics.
+    fn len(&self) -> usize {
+        unimplemented!("ConstInvalidIterator does not have a valid len");
+    }
+}
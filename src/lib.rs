@@ -9,16 +9,47 @@
 // allowed lints
 #![allow(clippy::match_bool)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod dedup_hint;
+mod delayed_hint;
+mod discover_len;
 mod exact_len;
 mod hint_size;
 mod invalid_iterator;
+#[cfg(feature = "log")]
+mod log_hint;
+mod on_exhaust;
+mod prefer_inner;
+#[cfg(feature = "std")]
+mod shared_len;
 mod size_hint;
 mod size_hinter;
+mod stateful_hint;
 mod test_iter;
+mod validated;
+#[cfg(feature = "alloc")]
+mod windows_hint;
+
+pub mod testing;
 
+pub use dedup_hint::*;
+pub use delayed_hint::*;
+pub use discover_len::*;
 pub use exact_len::*;
 pub use hint_size::*;
 pub use invalid_iterator::*;
+#[cfg(feature = "log")]
+pub use log_hint::*;
+pub use on_exhaust::*;
+pub use prefer_inner::*;
+#[cfg(feature = "std")]
+pub use shared_len::*;
 pub use size_hint::*;
 pub use size_hinter::*;
+pub use stateful_hint::*;
 pub use test_iter::*;
+pub use validated::*;
+#[cfg(feature = "alloc")]
+pub use windows_hint::*;
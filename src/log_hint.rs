@@ -0,0 +1,101 @@
+use core::cell::Cell;
+use core::iter::FusedIterator;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that logs the wrapped iterator's [`Iterator::size_hint`] via
+/// [`log::trace!`] whenever it is read and has changed since it was last logged.
+///
+/// The size hint is only observed when a consumer reads it, and logging only on change avoids spam
+/// from consumers that read it repeatedly. This is useful for debugging why a consumer over or
+/// under allocates.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut iter = (1..5).log_hint("my_iter");
+/// assert_eq!(iter.size_hint(), (4, Some(4)), "Unchanged size hint is not logged");
+///
+/// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+/// assert_eq!(iter.size_hint(), (3, Some(3)), "Changed size hint is logged");
+/// ```
+#[derive(Debug, Clone)]
+#[readonly::make]
+pub struct LogHint<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The target to log to.
+    pub target: &'static str,
+    /// The size hint that was last logged, or observed at construction.
+    last: Cell<(usize, Option<usize>)>,
+}
+
+impl<I: Iterator> LogHint<I> {
+    /// Wraps `iterator` in a new [`LogHint`] that logs to `target`.
+    ///
+    /// The initial size hint of `iterator` is recorded, but not logged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::LogHint;
+    /// let iter = LogHint::new(1..5, "my_iter");
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Size hint is not changed");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>, target: &'static str) -> Self {
+        let iterator = iterator.into_iter();
+        let last = Cell::new(iterator.size_hint());
+        Self { iterator, target, last }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::LogHint;
+    /// let iter = LogHint::new(1..5, "my_iter");
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator> Iterator for LogHint<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator.next()
+    }
+
+    /// Returns the wrapped iterator's size hint, logging it if it has changed since it was last
+    /// logged.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.iterator.size_hint();
+        let last = self.last.replace(hint);
+        if hint != last {
+            log::trace!(target: self.target, "size hint changed from {last:?} to {hint:?}");
+        }
+        hint
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for LogHint<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iterator.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for LogHint<I> {}
+
+impl<I: FusedIterator> FusedIterator for LogHint<I> {}
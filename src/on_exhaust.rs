@@ -0,0 +1,136 @@
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that runs a callback exactly once, the first time the wrapped iterator
+/// returns [`None`].
+///
+/// Only the first [`None`] is observed, so the callback fires at most once, even if the adaptor
+/// continues to be polled after exhaustion. This is useful for cleanup or logging when an iterator
+/// finishes.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// # use core::cell::Cell;
+/// let fired = Cell::new(0);
+/// let mut iter = (1..3).on_exhaust(|| fired.set(fired.get() + 1));
+///
+/// assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![1, 2], "Underlying iterator is not changed");
+/// assert_eq!(fired.get(), 1, "Callback fires on exhaustion");
+///
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(fired.get(), 1, "Callback fires only once");
+/// ```
+#[derive(Clone)]
+#[readonly::make]
+pub struct OnExhaust<I: Iterator, F: FnOnce()> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The callback, if it has not yet fired.
+    callback: Option<F>,
+}
+
+impl<I: Iterator, F: FnOnce()> OnExhaust<I, F> {
+    /// Wraps `iterator` in a new [`OnExhaust`] that runs `callback` when it is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::OnExhaust;
+    /// let iter = OnExhaust::new(1..5, || {});
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Size hint is not changed");
+    /// assert!(!iter.fired(), "Callback has not fired yet");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>, callback: F) -> Self {
+        Self { iterator: iterator.into_iter(), callback: Some(callback) }
+    }
+
+    /// Returns `true` if the callback has fired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::OnExhaust;
+    /// let mut iter = OnExhaust::new(1..2, || {});
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert!(!iter.fired(), "Callback has not fired yet");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert!(iter.fired(), "Callback has fired");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fired(&self) -> bool {
+        self.callback.is_none()
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// The callback is dropped without being run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::OnExhaust;
+    /// let iter = OnExhaust::new(1..5, || {});
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+
+    /// Runs the callback if `item` is [`None`] and it has not yet fired.
+    #[inline]
+    fn check(&mut self, item: Option<I::Item>) -> Option<I::Item> {
+        if item.is_none() {
+            if let Some(callback) = self.callback.take() {
+                callback();
+            }
+        }
+        item
+    }
+}
+
+impl<I: Iterator, F: FnOnce()> Iterator for OnExhaust<I, F> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next();
+        self.check(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, F: FnOnce()> DoubleEndedIterator for OnExhaust<I, F> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next_back();
+        self.check(item)
+    }
+}
+
+impl<I: ExactSizeIterator, F: FnOnce()> ExactSizeIterator for OnExhaust<I, F> {}
+
+impl<I: FusedIterator, F: FnOnce()> FusedIterator for OnExhaust<I, F> {}
+
+impl<I: Iterator + Debug, F: FnOnce()> Debug for OnExhaust<I, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnExhaust")
+            .field("iterator", &self.iterator)
+            .field("fired", &self.fired())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,107 @@
+use core::iter::FusedIterator;
+
+use crate::SizeHint;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that tracks a size hint, but reports the wrapped iterator's size hint
+/// instead whenever it is exact.
+///
+/// Some iterators (for example [`Take`](core::iter::Take) or [`Chain`](core::iter::Chain)) may
+/// produce an exact size hint partway through iteration. This adaptor yields the tightest
+/// available information, preferring the wrapped iterator's exact size hint over its own tracked
+/// one.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut iter = (1..).take(5).hint_size(2, 8).prefer_inner_when_exact();
+///
+/// assert_eq!(iter.size_hint(), (5, Some(5)), "Should prefer the inner exact size hint");
+/// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+/// assert_eq!(iter.size_hint(), (4, Some(4)), "Should prefer the inner exact size hint");
+/// ```
+#[derive(Debug, Clone)]
+#[readonly::make]
+pub struct PreferInner<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The tracked size hint, used when the underlying iterator's size hint is not exact.
+    pub hint: SizeHint,
+}
+
+impl<I: Iterator> PreferInner<I> {
+    /// Wraps `iterator` in a new [`PreferInner`], tracking `iterator`'s initial size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iterator`'s [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::PreferInner;
+    /// let iter = PreferInner::new((1..5).filter(|x| x % 2 == 0));
+    /// assert_eq!(iter.size_hint(), (0, Some(4)), "Should match the initial size hint");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>) -> Self {
+        let iterator = iterator.into_iter();
+        let hint = iterator.size_hint().try_into().expect("iterator's size hint should be valid");
+        Self { iterator, hint }
+    }
+
+    /// Wraps `iterator` in a new [`PreferInner`], tracking `hint`.
+    ///
+    /// `hint` is expected to be validated against `iterator` by the caller.
+    #[inline]
+    pub(crate) const fn with_hint(iterator: I, hint: SizeHint) -> Self {
+        Self { iterator, hint }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::PreferInner;
+    /// let iter = PreferInner::new(1..5);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator> Iterator for PreferInner<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.hint = self.hint.decrement();
+        self.iterator.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iterator.size_hint() {
+            inner @ (lower, Some(upper)) if lower == upper => inner,
+            _ => self.hint.into(),
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for PreferInner<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.hint = self.hint.decrement();
+        self.iterator.next_back()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for PreferInner<I> {}
@@ -0,0 +1,122 @@
+use alloc::sync::Arc;
+use core::iter::FusedIterator;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that publishes the lower bound of the wrapped iterator's
+/// [`Iterator::size_hint`] to a shared [`AtomicUsize`] as it is advanced.
+///
+/// The lower bound is published each time the iterator is advanced from either end. The shared
+/// handle returned by [`SharedLen::handle`] can be read from another thread without borrowing the
+/// iterator, for example to report progress.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// # use core::sync::atomic::Ordering;
+/// let (mut iter, remaining) = (1..5).shared_len();
+/// assert_eq!(remaining.load(Ordering::Relaxed), 4, "Should start with the initial lower bound");
+///
+/// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+/// assert_eq!(remaining.load(Ordering::Relaxed), 3, "Should reflect new state");
+/// ```
+#[derive(Debug)]
+#[readonly::make]
+pub struct SharedLen<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The shared lower bound of the underlying iterator's size hint.
+    pub remaining: Arc<AtomicUsize>,
+}
+
+impl<I: Iterator> SharedLen<I> {
+    /// Wraps `iterator` in a new [`SharedLen`], initialized with its current lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SharedLen;
+    /// # use core::sync::atomic::Ordering;
+    /// let iter = SharedLen::new(1..5);
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Size hint is not changed");
+    /// assert_eq!(iter.handle().load(Ordering::Relaxed), 4);
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>) -> Self {
+        let iterator = iterator.into_iter();
+        let remaining = Arc::new(AtomicUsize::new(iterator.size_hint().0));
+        Self { iterator, remaining }
+    }
+
+    /// Returns a new handle to the shared lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SharedLen;
+    /// # use core::sync::atomic::Ordering;
+    /// let mut iter = SharedLen::new(1..5);
+    /// let remaining = iter.handle();
+    ///
+    /// iter.next();
+    /// assert_eq!(remaining.load(Ordering::Relaxed), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn handle(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.remaining)
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SharedLen;
+    /// let iter = SharedLen::new(1..5);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+
+    /// Publishes the current lower bound of the wrapped iterator.
+    #[inline]
+    fn publish(&self, item: Option<I::Item>) -> Option<I::Item> {
+        self.remaining.store(self.iterator.size_hint().0, Ordering::Relaxed);
+        item
+    }
+}
+
+impl<I: Iterator> Iterator for SharedLen<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next();
+        self.publish(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for SharedLen<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next_back();
+        self.publish(item)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for SharedLen<I> {}
+
+impl<I: FusedIterator> FusedIterator for SharedLen<I> {}
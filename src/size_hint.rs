@@ -1,10 +1,59 @@
-use core::ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::array;
+use core::fmt::{self, Display, Formatter};
+use core::num::NonZeroUsize;
+use core::ops::{Add, Bound, Mul, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
 /// Error type for reporting invalid size hints where the size hint would be empty or invalid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid size hint: values describe an invalid or empty range")]
 pub struct InvalidSizeHint;
 
+/// Error type for reporting which bound overflowed when scaling a [`SizeHint`].
+///
+/// See [`SizeHint::try_scale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum ScaleOverflow {
+    /// The lower bound overflowed. As the upper bound is never less than the lower bound, it also
+    /// overflowed, if present.
+    #[error("scaling the size hint lower bound overflowed")]
+    Lower,
+    /// The upper bound overflowed, but the lower bound did not.
+    #[error("scaling the size hint upper bound overflowed")]
+    Upper,
+}
+
+/// A policy for computing a capacity to preallocate from a [`SizeHint`].
+///
+/// See [`SizeHint::capacity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CapacityPolicy {
+    /// Use the lower bound. This never over allocates, but may require reallocation.
+    #[default]
+    Lower,
+    /// Use the upper bound, or the lower bound if unbounded.
+    Upper,
+    /// Use the midpoint between the lower and upper bound (rounded down), or the lower bound if
+    /// unbounded.
+    Midpoint,
+    /// Use the upper bound, or the provided value if unbounded. Never less than the lower bound.
+    UpperOr(usize),
+}
+
+/// A binary operation for combining two [`SizeHint`]s.
+///
+/// See [`SizeHint::combine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CombineOp {
+    /// The smallest size hint containing both size hints.
+    Union,
+    /// The range of lengths both size hints admit. Fails if the size hints are disjoint.
+    Intersection,
+    /// The size hint of the two iterators chained together, as in [`Iterator::chain`].
+    Sum,
+    /// The size hint of the two iterators zipped together, as in [`Iterator::zip`].
+    Zip,
+}
+
 /// A size hint for an iterator.
 ///
 /// This is an immutable wrapper around the standard iterator size hint tuple
@@ -19,7 +68,11 @@ pub struct InvalidSizeHint;
 ///
 /// A size hint can never describe an empty range, as 0 is always a valid number of elements
 /// remaining for an iterator.
+///
+/// With the `serde` feature, a size hint is (de)serialized as a struct with `lower` and `upper`
+/// fields. Deserializing an invalid size hint, one where `lower > upper`, fails.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[readonly::make]
 pub struct SizeHint {
     /// The inclusive lower bound of the size hint.
@@ -35,6 +88,9 @@ impl SizeHint {
     /// A size hint that indicates that the iterator will yield no elements.
     pub const ZERO: Self = Self { lower: 0, upper: Some(0) };
 
+    /// A size hint that indicates that the iterator is exhausted, equal to [`SizeHint::ZERO`].
+    pub const EMPTY: Self = Self::ZERO;
+
     /// Creates a new size hint with the given lower and optional upper bounds.
     ///
     /// # Panics
@@ -108,6 +164,38 @@ impl SizeHint {
         }
     }
 
+    /// Creates a new size hint with the given lower and optional upper bounds, panicking with the
+    /// offending values if they are invalid.
+    ///
+    /// This is equivalent to [`Self::new`], but with a more informative panic message, which is
+    /// useful when developing adaptors that compute bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lower` is greater than `upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::checked(5, Some(10));
+    /// assert_eq!(hint, SizeHint::bounded(5, 10));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::checked(10, Some(5)); // panics with "invalid hint: lower 10 > upper 5"
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn checked(lower: usize, upper: Option<usize>) -> Self {
+        match upper {
+            Some(upper) if lower > upper => panic!("invalid hint: lower {lower} > upper {upper}"),
+            _ => Self { lower, upper },
+        }
+    }
+
     /// Tries to create a new bounded [`SizeHint`] with the given `lower` and `upper` bounds.
     ///
     /// # Errors
@@ -163,6 +251,54 @@ impl SizeHint {
         Self { lower: len, upper: Some(len) }
     }
 
+    /// Creates a new size hint of zero or one elements, `(0, Some(1))`.
+    ///
+    /// This is the size hint of iterators that yield at most a single element, like the iterators
+    /// of [`Option`] and [`Result`], when it is not known whether the element is present.
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::zero_or_one(), SizeHint::bounded(0, 1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn zero_or_one() -> Self {
+        Self::at_most(1)
+    }
+
+    /// Creates a new exact size hint of one element if `present`, or zero elements otherwise.
+    ///
+    /// This is the size hint of a conditional single element iterator, such as
+    /// [`bool::then_some`] followed by [`Option::into_iter`].
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::from_bool(true), SizeHint::exact(1));
+    /// assert_eq!(SizeHint::from_bool(false), SizeHint::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bool(present: bool) -> Self {
+        Self::exact(present as usize)
+    }
+
+    /// Creates a new size hint with an exact count from a `u32`.
+    ///
+    /// This is a convenience for building `const` size hints on platforms where `u32` always fits
+    /// in a `usize`. Only available on 32 and 64 bit targets.
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// const HINT: SizeHint = SizeHint::exact_u32(5);
+    /// assert_eq!(HINT, SizeHint::exact(5));
+    /// ```
+    #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+    #[inline]
+    #[must_use]
+    pub const fn exact_u32(len: u32) -> Self {
+        Self::exact(len as usize)
+    }
+
     /// Creates a new size hint with the given upper bound and a lower bound of 0.
     ///
     /// A common use case for this is [`Filter`](core::iter::Filter).
@@ -179,6 +315,242 @@ impl SizeHint {
         Self { lower: 0, upper: Some(upper) }
     }
 
+    /// Creates a new size hint from a best-guess count and a known hard maximum, that is,
+    /// `(0, Some(min(guess, max)))`.
+    ///
+    /// The guess is conservatively treated as an upper bound, with a lower bound of 0. This is for
+    /// the common "about `guess`, but definitely no more than `max`" scenario. If `guess > max`,
+    /// the guess is clamped to `max` rather than rejected. As the lower bound is 0, the result is
+    /// always valid, so this is infallible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::estimate(5, 10), SizeHint::at_most(5));
+    /// assert_eq!(SizeHint::estimate(20, 10), SizeHint::at_most(10));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn estimate(guess: usize, max: usize) -> Self {
+        Self::at_most(if guess < max { guess } else { max })
+    }
+
+    /// Creates a new size hint with an upper bound from a floating point `estimate`, that is,
+    /// `(0, Some(ceil(estimate)))`.
+    ///
+    /// This is for capacity calculations done in floating point, such as `avg_len * count`. The
+    /// estimate is clamped as follows:
+    ///
+    /// - [`NaN`](f64::NAN) and negative estimates (including negative infinity) result in [`Self::ZERO`].
+    /// - Estimates whose ceiling is greater than or equal to `usize::MAX as f64` (including
+    ///   positive infinity) can not be represented, and result in [`Self::UNIVERSAL`], that is,
+    ///   `unbounded(0)`.
+    /// - All other estimates are rounded up to the next integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::from_f64_upper(4.2), SizeHint::at_most(5));
+    /// assert_eq!(SizeHint::from_f64_upper(f64::NAN), SizeHint::ZERO);
+    /// assert_eq!(SizeHint::from_f64_upper(-1.0), SizeHint::ZERO);
+    /// assert_eq!(SizeHint::from_f64_upper(f64::INFINITY), SizeHint::UNIVERSAL);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    pub const fn from_f64_upper(estimate: f64) -> Self {
+        if estimate.is_nan() || estimate < 0.0 {
+            return Self::ZERO;
+        }
+        if estimate >= usize::MAX as f64 {
+            return Self::UNIVERSAL;
+        }
+        // `ceil` is not available in `core`, truncate and round up any fractional part instead.
+        let truncated = estimate as usize;
+        Self::at_most(if (truncated as f64) < estimate { truncated + 1 } else { truncated })
+    }
+
+    /// Tries to create a new size hint from the exclusive `end` of a [`RangeTo`], that is,
+    /// `(0, Some(end - 1))`.
+    ///
+    /// This is a `const` equivalent of the [`TryFrom<RangeTo<usize>>`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `end` is 0, as the range would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// const HINT: Result<SizeHint, InvalidSizeHint> = SizeHint::from_range_to(8);
+    /// assert_eq!(HINT, Ok(SizeHint::at_most(7)));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::from_range_to(0).expect_err("range should be empty");
+    /// ```
+    #[inline]
+    pub const fn from_range_to(end: usize) -> Result<Self, InvalidSizeHint> {
+        match end.checked_sub(1) {
+            Some(upper) => Ok(Self::at_most(upper)),
+            None => Err(InvalidSizeHint),
+        }
+    }
+
+    /// Tries to create a new size hint from any [`RangeBounds`].
+    ///
+    /// An unbounded start is interpreted as a lower bound of 0, and an unbounded end as no upper
+    /// bound. Excluded bounds are converted to the equivalent included bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the range is inverted or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::from_range_bounds(3..8)?, SizeHint::bounded(3, 7));
+    /// assert_eq!(SizeHint::from_range_bounds(3..=7)?, SizeHint::bounded(3, 7));
+    /// assert_eq!(SizeHint::from_range_bounds(5..)?, SizeHint::unbounded(5));
+    /// assert_eq!(SizeHint::from_range_bounds(..)?, SizeHint::UNIVERSAL);
+    ///
+    /// let err: InvalidSizeHint = SizeHint::from_range_bounds(5..5).expect_err("range should be empty");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_range_bounds<R: RangeBounds<usize>>(bounds: R) -> Result<Self, InvalidSizeHint> {
+        let lower = match bounds.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).ok_or(InvalidSizeHint)?,
+            Bound::Unbounded => 0,
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(&end) => Some(end),
+            Bound::Excluded(&end) => Some(end.checked_sub(1).ok_or(InvalidSizeHint)?),
+            Bound::Unbounded => None,
+        };
+        Self::try_new(lower, upper)
+    }
+
+    /// Creates a new size hint from the current [`Iterator::size_hint`] of `iter`.
+    ///
+    /// This is a snapshot of the current size hint, and will not change as `iter` is advanced.
+    /// See also [`SizeHinter::to_size_hint`](crate::SizeHinter::to_size_hint).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `iter`'s size hint is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint, INVALID_UNIT_ITERATOR};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = (1..5).filter(|x| x % 2 == 0);
+    /// assert_eq!(SizeHint::of(&iter)?, SizeHint::at_most(4));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::of(&INVALID_UNIT_ITERATOR).expect_err("Size hint should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn of<I: Iterator>(iter: &I) -> Result<Self, InvalidSizeHint> {
+        iter.size_hint().try_into()
+    }
+
+    /// Tries to create a new size hint from the `Option` free representation produced by
+    /// [`Self::to_ffi`].
+    ///
+    /// If `has_upper` is `false`, the hint is unbounded and `upper` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `has_upper` is `true` and `lower` is greater than `upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::from_ffi(3, 7, true)?, SizeHint::bounded(3, 7));
+    /// assert_eq!(SizeHint::from_ffi(3, 0, false)?, SizeHint::unbounded(3));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::from_ffi(7, 3, true).expect_err("SizeHint should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn from_ffi(lower: usize, upper: usize, has_upper: bool) -> Result<Self, InvalidSizeHint> {
+        match has_upper {
+            true => Self::try_bounded(lower, upper),
+            false => Ok(Self::unbounded(lower)),
+        }
+    }
+
+    /// Tries to create a new size hint from the `(lower, upper)` pair produced by
+    /// [`Self::to_pair_with_sentinel`], treating an `upper` equal to `sentinel` as unbounded.
+    ///
+    /// Note that a bounded size hint whose upper bound equals `sentinel` cannot be represented,
+    /// and will round trip as unbounded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `upper` is not `sentinel` and `lower` is greater than `upper`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::from_pair_with_sentinel(3, 7, usize::MAX)?, SizeHint::bounded(3, 7));
+    /// assert_eq!(SizeHint::from_pair_with_sentinel(3, usize::MAX, usize::MAX)?, SizeHint::unbounded(3));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::from_pair_with_sentinel(7, 3, usize::MAX).expect_err("SizeHint should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn from_pair_with_sentinel(lower: usize, upper: usize, sentinel: usize) -> Result<Self, InvalidSizeHint> {
+        Self::from_ffi(lower, upper, upper != sentinel)
+    }
+
+    /// Tries to create a new size hint from the compact binary encoding produced by
+    /// [`Self::to_le_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if:
+    /// - the presence flag is neither 0 nor 1
+    /// - a present bound does not fit in a `usize`
+    /// - the upper bound is present and less than the lower bound
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let bytes = SizeHint::bounded(3, 7).to_le_bytes();
+    /// assert_eq!(SizeHint::from_le_bytes(bytes)?, SizeHint::bounded(3, 7));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::from_le_bytes([2; 17]).expect_err("flag should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 17]) -> Result<Self, InvalidSizeHint> {
+        let lower = u64::from_le_bytes(array::from_fn(|i| bytes[i]));
+        let upper = u64::from_le_bytes(array::from_fn(|i| bytes[8 + i]));
+        match (bytes[16], usize::try_from(lower), usize::try_from(upper)) {
+            (0, Ok(lower), _) => Ok(Self::unbounded(lower)),
+            (1, Ok(lower), Ok(upper)) => Self::try_bounded(lower, upper),
+            _ => Err(InvalidSizeHint),
+        }
+    }
+
     /// Returns the inclusive lower bound of the size hint.
     ///
     /// # Examples
@@ -209,6 +581,43 @@ impl SizeHint {
         self.upper
     }
 
+    /// Returns the lower bound of the size hint as a [`NonZeroUsize`], or [`None`] if it is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// # use core::num::NonZeroUsize;
+    /// assert_eq!(SizeHint::bounded(5, 10).lower_nonzero(), NonZeroUsize::new(5));
+    /// assert_eq!(SizeHint::at_most(10).lower_nonzero(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lower_nonzero(self) -> Option<NonZeroUsize> {
+        NonZeroUsize::new(self.lower)
+    }
+
+    /// Returns the upper bound of the size hint as a [`NonZeroUsize`], or [`None`] if it is 0 or
+    /// unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// # use core::num::NonZeroUsize;
+    /// assert_eq!(SizeHint::bounded(5, 10).upper_nonzero(), NonZeroUsize::new(10));
+    /// assert_eq!(SizeHint::ZERO.upper_nonzero(), None);
+    /// assert_eq!(SizeHint::unbounded(5).upper_nonzero(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn upper_nonzero(self) -> Option<NonZeroUsize> {
+        match self.upper {
+            Some(upper) => NonZeroUsize::new(upper),
+            None => None,
+        }
+    }
+
     /// Returns the size hint as a tuple `(lower, upper)`.
     ///
     /// # Examples
@@ -224,97 +633,1155 @@ impl SizeHint {
         (self.lower, self.upper)
     }
 
-    /// Returns a new [`SizeHint`] with the lower and upper bounds (if present) decremented by 1.
+    /// Returns the size hint as a `(lower, upper)` tuple of [`f64`], for floating point consumers.
     ///
-    /// This is useful for decrementing the size hint of an iterator after it has been advanced.
+    /// Bounds greater than 2^53 can not be represented exactly, and are rounded to the nearest
+    /// representable [`f64`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use size_hinter::SizeHint;
-    /// let hint = SizeHint::bounded(5, 10);
-    /// assert_eq!(hint.decrement(), SizeHint::bounded(4, 9));
+    /// assert_eq!(SizeHint::bounded(3, 7).as_f64(), (3.0, Some(7.0)));
+    /// assert_eq!(SizeHint::unbounded(3).as_f64(), (3.0, None));
     /// ```
     #[inline]
     #[must_use]
-    pub fn decrement(self) -> Self {
-        Self { lower: self.lower.saturating_sub(1), upper: self.upper.map(|upper| upper.saturating_sub(1)) }
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn as_f64(self) -> (f64, Option<f64>) {
+        match self.upper {
+            Some(upper) => (self.lower as f64, Some(upper as f64)),
+            None => (self.lower as f64, None),
+        }
     }
 
-    /// Returns `true` if this size hint range overlaps with another size hint range.
+    /// Returns a measure of how informative this size hint is, in the range `(0, 1]`.
     ///
-    /// Two ranges overlap if there exists at least one value that could be contained in both.
-    /// This operation is the negation of [`Self::disjoint`], and is also commutative.
+    /// This is computed as `1 / (1 + (upper - lower))`, so that an exact size hint is `1.0`, and
+    /// wider ranges approach 0. Returns [`None`] if the size hint is unbounded, as it carries no
+    /// information about the upper bound. This is a diagnostic for comparing the quality of size
+    /// hints from different sources.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use size_hinter::SizeHint;
-    /// assert!(SizeHint::overlaps(SizeHint::bounded(3, 6), SizeHint::bounded(5, 10)), "should overlap at 5 and 6");
-    /// assert!(SizeHint::overlaps(SizeHint::exact(5), SizeHint::UNIVERSAL), "Universal should overlap with any size hint");
-    /// assert!(!SizeHint::overlaps(SizeHint::unbounded(11), SizeHint::exact(5)), "should not overlap");
-    /// assert!(SizeHint::overlaps(SizeHint::unbounded(11), SizeHint::UNIVERSAL), "two unbounded hints should overlap");
+    /// assert_eq!(SizeHint::exact(5).information(), Some(1.0));
+    /// assert_eq!(SizeHint::bounded(3, 7).information(), Some(0.2));
+    /// assert_eq!(SizeHint::unbounded(5).information(), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn overlaps(self, other: Self) -> bool {
-        match (self.as_hint(), other.as_hint()) {
-            ((a_lower, Some(a_upper)), (b_lower, Some(b_upper))) => a_lower <= b_upper && b_lower <= a_upper,
-            ((_, Some(a_upper)), (b_lower, None)) => a_upper >= b_lower,
-            ((a_lower, None), (_, Some(b_upper))) => b_upper >= a_lower,
-            ((_, None), (_, None)) => true,
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn information(self) -> Option<f64> {
+        match self.upper {
+            Some(upper) => Some(1.0 / (1.0 + (upper - self.lower) as f64)),
+            None => None,
         }
     }
 
-    /// Returns `true` if this size hint range is disjoint with another range.
+    /// Returns the size hint as a C friendly `(lower, upper, has_upper)` tuple, without an [`Option`].
     ///
-    /// Two ranges are disjoint if there exists no value that could be contained in both.
-    /// This operation is the negation of [`Self::overlaps`], and is also commutative.
+    /// For unbounded hints, `has_upper` is `false` and `upper` is 0. The inverse of this
+    /// operation is [`Self::from_ffi`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use size_hinter::SizeHint;
-    /// assert!(SizeHint::disjoint(SizeHint::exact(5), SizeHint::unbounded(10)), "should be disjoint");
-    /// assert!(SizeHint::disjoint(SizeHint::exact(5), SizeHint::bounded(6, 10)), "should be disjoint");
-    /// assert!(!SizeHint::disjoint(SizeHint::unbounded(11), SizeHint::UNIVERSAL), "two unbounded hints should never be disjoint");
+    /// assert_eq!(SizeHint::bounded(3, 7).to_ffi(), (3, 7, true));
+    /// assert_eq!(SizeHint::unbounded(3).to_ffi(), (3, 0, false));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn disjoint(self, other: Self) -> bool {
-        !Self::overlaps(self, other)
+    pub const fn to_ffi(self) -> (usize, usize, bool) {
+        match self.upper {
+            Some(upper) => (self.lower, upper, true),
+            None => (self.lower, 0, false),
+        }
     }
 
-    /// Returns `true` if this size hint range is completely contained within another range.
+    /// Returns the size hint as a `(lower, upper)` pair, using `sentinel` as the upper bound of an
+    /// unbounded size hint.
     ///
-    /// This operation is not commutative, i.e. `a.subset_of(b)` does not imply `b.subset_of(a)`.
+    /// This gives a compact representation for dense storage. The inverse of this operation is
+    /// [`Self::from_pair_with_sentinel`]. Note that a bounded size hint whose upper bound equals
+    /// `sentinel` is indistinguishable from an unbounded one in this representation.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use size_hinter::SizeHint;
-    /// assert!(SizeHint::bounded(4, 6).subset_of(SizeHint::bounded(4, 6)), "should be a subset (equal ranges)");
-    /// assert!(SizeHint::bounded(4, 6).subset_of(SizeHint::bounded(3, 9)), "should be a subset");
-    /// assert!(!SizeHint::bounded(3, 9).subset_of(SizeHint::bounded(4, 6)), "should not be a subset (not commutative)");
-    /// assert!(!SizeHint::bounded(2, 6).subset_of(SizeHint::bounded(3, 6)), "should not be a subset (lower bound)");
-    /// assert!(!SizeHint::bounded(4, 7).subset_of(SizeHint::bounded(3, 6)), "should not be a subset (upper bound)");
+    /// assert_eq!(SizeHint::bounded(3, 7).to_pair_with_sentinel(usize::MAX), (3, 7));
+    /// assert_eq!(SizeHint::unbounded(3).to_pair_with_sentinel(usize::MAX), (3, usize::MAX));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn subset_of(self, other: Self) -> bool {
-        match (self.as_hint(), other.as_hint()) {
-            ((a_low, Some(a_up)), (b_low, Some(b_up))) => a_low >= b_low && a_up <= b_up,
-            ((a_low, _), (b_low, None)) => a_low >= b_low,
-            ((_, None), (_, Some(_))) => false,
+    pub const fn to_pair_with_sentinel(self, sentinel: usize) -> (usize, usize) {
+        match self.upper {
+            Some(upper) => (self.lower, upper),
+            None => (self.lower, sentinel),
         }
     }
-}
-
-impl TryFrom<(usize, Option<usize>)> for SizeHint {
-    type Error = InvalidSizeHint;
 
+    /// Returns a compact, platform independent binary encoding of the size hint.
+    ///
+    /// The encoding is the lower bound as a little endian `u64` (8 bytes), followed by the upper
+    /// bound as a little endian `u64` (8 bytes, 0 if unbounded), followed by a presence flag for
+    /// the upper bound (1 byte, 0 or 1). The inverse of this operation is [`Self::from_le_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let bytes = SizeHint::bounded(3, 7).to_le_bytes();
+    /// assert_eq!(bytes[0], 3);
+    /// assert_eq!(bytes[8], 7);
+    /// assert_eq!(bytes[16], 1);
+    ///
+    /// assert_eq!(SizeHint::unbounded(3).to_le_bytes()[16], 0);
+    /// ```
     #[inline]
-    fn try_from(hint: (usize, Option<usize>)) -> Result<Self, Self::Error> {
+    #[must_use]
+    pub fn to_le_bytes(self) -> [u8; 17] {
+        let (lower, upper, has_upper) = self.to_ffi();
+        let mut bytes = [0; 17];
+        bytes[..8].copy_from_slice(&(lower as u64).to_le_bytes());
+        bytes[8..16].copy_from_slice(&(upper as u64).to_le_bytes());
+        bytes[16] = u8::from(has_upper);
+        bytes
+    }
+
+    /// Returns `true` if this size hint indicates that the iterator will yield no elements, that
+    /// is, if its upper bound is 0.
+    ///
+    /// As the lower bound may not exceed the upper bound, this is only the case for
+    /// [`SizeHint::EMPTY`]. This is useful for deciding whether to allocate at all before collecting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::EMPTY.is_empty());
+    /// assert!(!SizeHint::at_most(5).is_empty());
+    /// assert!(!SizeHint::UNIVERSAL.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        matches!(self.upper, Some(0))
+    }
+
+    /// Returns the exact length described by this size hint, or [`None`] if it is not exact.
+    ///
+    /// A size hint is exact when its lower bound equals its upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(5).exact_len(), Some(5));
+    /// assert_eq!(SizeHint::bounded(3, 5).exact_len(), None);
+    /// assert_eq!(SizeHint::unbounded(5).exact_len(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn exact_len(self) -> Option<usize> {
+        match self.upper {
+            Some(upper) if upper == self.lower => Some(upper),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this size hint is exact, that is, if its lower bound equals its upper
+    /// bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::exact(5).is_exact());
+    /// assert!(!SizeHint::bounded(3, 5).is_exact());
+    /// assert!(!SizeHint::unbounded(5).is_exact());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_exact(self) -> bool {
+        self.exact_len().is_some()
+    }
+
+    /// Returns the exact length described by this size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if this size hint is not exact (`lower != upper`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(5).expect_exact("hint should be exact"), 5);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use size_hinter::SizeHint;
+    /// let _ = SizeHint::bounded(3, 5).expect_exact("hint should be exact");
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn expect_exact(self, msg: &str) -> usize {
+        let Some(len) = self.exact_len() else { panic!("{msg}") };
+        len
+    }
+
+    /// Returns a new [`SizeHint`] with the lower and upper bounds (if present) decremented by 1.
+    ///
+    /// This is useful for decrementing the size hint of an iterator after it has been advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(5, 10);
+    /// assert_eq!(hint.decrement(), SizeHint::bounded(4, 9));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn decrement(self) -> Self {
+        self.saturating_sub(1)
+    }
+
+    /// Returns a new [`SizeHint`] with the lower and upper bounds (if present) reduced by `n`,
+    /// saturating at 0.
+    ///
+    /// This is useful for updating the size hint of an iterator after it has been advanced by
+    /// several elements at once, for example by [`Iterator::nth`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(5, 9).saturating_sub(3), SizeHint::bounded(2, 6));
+    /// assert_eq!(SizeHint::bounded(5, 9).saturating_sub(7), SizeHint::at_most(2));
+    /// assert_eq!(SizeHint::unbounded(5).saturating_sub(3), SizeHint::unbounded(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(self, n: usize) -> Self {
+        match self.upper {
+            Some(upper) => Self { lower: self.lower.saturating_sub(n), upper: Some(upper.saturating_sub(n)) },
+            None => Self { lower: self.lower.saturating_sub(n), upper: None },
+        }
+    }
+
+    /// Returns a new [`SizeHint`] with the lower and upper bounds (if present) decremented by 1,
+    /// or [`None`] if this size hint indicates no elements remain (an upper bound of 0).
+    ///
+    /// Unlike [`Self::decrement`], this signals exhaustion rather than saturating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(1).checked_decrement(), Some(SizeHint::ZERO));
+    /// assert_eq!(SizeHint::ZERO.checked_decrement(), None);
+    /// assert_eq!(SizeHint::unbounded(0).checked_decrement(), Some(SizeHint::unbounded(0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_decrement(self) -> Option<Self> {
+        match self.upper {
+            Some(0) => None,
+            Some(upper) => Some(Self { lower: self.lower.saturating_sub(1), upper: Some(upper - 1) }),
+            None => Some(Self { lower: self.lower.saturating_sub(1), upper: None }),
+        }
+    }
+
+    /// Tries to return a new [`SizeHint`] with the lower bound raised by `n`, saturating at
+    /// `usize::MAX`. The upper bound is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the raised lower bound would exceed the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::bounded(2, 10).add_lower(3)?, SizeHint::bounded(5, 10));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::bounded(2, 4).add_lower(3).expect_err("lower should exceed upper");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn add_lower(self, n: usize) -> Result<Self, InvalidSizeHint> {
+        Self::try_new(self.lower.saturating_add(n), self.upper)
+    }
+
+    /// Tries to return a new [`SizeHint`] with the lower bound raised to at least 1, enforcing
+    /// that the described iterator is non-empty. The upper bound is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the upper bound is 0, as the size hint then contradicts the
+    /// iterator being non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::at_most(5).at_least_nonempty()?, SizeHint::bounded(1, 5));
+    /// assert_eq!(SizeHint::bounded(3, 5).at_least_nonempty()?, SizeHint::bounded(3, 5));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::ZERO.at_least_nonempty().expect_err("should be empty");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn at_least_nonempty(self) -> Result<Self, InvalidSizeHint> {
+        Self::try_new(if self.lower == 0 { 1 } else { self.lower }, self.upper)
+    }
+
+    /// Returns a new [`SizeHint`] with the upper bound (if present) raised by `n`, saturating at
+    /// `usize::MAX`. The lower bound is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(2, 4).add_upper(3), SizeHint::bounded(2, 7));
+    /// assert_eq!(SizeHint::unbounded(2).add_upper(3), SizeHint::unbounded(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn add_upper(self, n: usize) -> Self {
+        match self.upper {
+            Some(upper) => Self { lower: self.lower, upper: Some(upper.saturating_add(n)) },
+            None => self,
+        }
+    }
+
+    /// Returns the [`SizeHint`] of a sliding window adaptor with windows of size `window` over an
+    /// iterator with this size hint.
+    ///
+    /// A sliding window of size `w` over `n` elements yields `n - w + 1` windows, or 0 if `n < w`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(10).windowed(3), SizeHint::exact(8));
+    /// assert_eq!(SizeHint::exact(2).windowed(5), SizeHint::ZERO);
+    /// assert_eq!(SizeHint::unbounded(4).windowed(2), SizeHint::unbounded(3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn windowed(self, window: usize) -> Self {
+        assert!(window != 0, "window size should be non-zero");
+        let lower = self.lower.saturating_sub(window - 1);
+        match self.upper {
+            Some(upper) => Self { lower, upper: Some(upper.saturating_sub(window - 1)) },
+            None => Self { lower, upper: None },
+        }
+    }
+
+    /// Returns this size hint with both bounds multiplied by `factor`, saturating at `usize::MAX`.
+    ///
+    /// A `factor` of 0 always produces [`SizeHint::ZERO`], even if unbounded. An unbounded upper
+    /// bound otherwise remains unbounded. This models a `flat_map` where each inner iterator yields
+    /// exactly `factor` elements, and backs the [`Mul<usize>`](Mul) implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(2, 5).scale(3), SizeHint::bounded(6, 15));
+    /// assert_eq!(SizeHint::unbounded(2).scale(3), SizeHint::unbounded(6));
+    /// assert_eq!(SizeHint::UNIVERSAL.scale(0), SizeHint::ZERO);
+    /// assert_eq!(SizeHint::at_most(usize::MAX).scale(2), SizeHint::at_most(usize::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn scale(self, factor: usize) -> Self {
+        match (factor, self.upper) {
+            (0, _) => Self::ZERO,
+            (_, Some(upper)) => {
+                Self { lower: self.lower.saturating_mul(factor), upper: Some(upper.saturating_mul(factor)) }
+            }
+            (_, None) => Self { lower: self.lower.saturating_mul(factor), upper: None },
+        }
+    }
+
+    /// Returns this size hint with both bounds multiplied by `factor`, or an error identifying the
+    /// bound that overflowed.
+    ///
+    /// Unlike [`Self::scale`], this does not saturate. A `factor` of 0 always produces
+    /// [`SizeHint::ZERO`], even if unbounded. An unbounded upper bound otherwise remains
+    /// unbounded, and never overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScaleOverflow::Lower`] if the lower bound overflows, or [`ScaleOverflow::Upper`]
+    /// if only the upper bound overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, ScaleOverflow};
+    /// # fn main() -> Result<(), ScaleOverflow> {
+    /// assert_eq!(SizeHint::bounded(2, 5).try_scale(3)?, SizeHint::bounded(6, 15));
+    /// assert_eq!(SizeHint::unbounded(usize::MAX).try_scale(2), Err(ScaleOverflow::Lower));
+    /// assert_eq!(SizeHint::at_most(usize::MAX).try_scale(2), Err(ScaleOverflow::Upper));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn try_scale(self, factor: usize) -> Result<Self, ScaleOverflow> {
+        let Some(lower) = self.lower.checked_mul(factor) else {
+            return Err(ScaleOverflow::Lower);
+        };
+        match (factor, self.upper) {
+            (0, _) => Ok(Self::ZERO),
+            (_, None) => Ok(Self { lower, upper: None }),
+            (_, Some(upper)) => match upper.checked_mul(factor) {
+                Some(upper) => Ok(Self { lower, upper: Some(upper) }),
+                None => Err(ScaleOverflow::Upper),
+            },
+        }
+    }
+
+    /// Returns the size hint of an iterator with this size hint chained with itself `times` times.
+    ///
+    /// Both bounds are multiplied by `times`, saturating at `usize::MAX`. Repeating 0 times always
+    /// produces [`SizeHint::ZERO`], even if unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(3).repeat(4), SizeHint::exact(12));
+    /// assert_eq!(SizeHint::unbounded(3).repeat(2), SizeHint::unbounded(6));
+    /// assert_eq!(SizeHint::unbounded(3).repeat(0), SizeHint::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn repeat(self, times: usize) -> Self {
+        self.scale(times)
+    }
+
+    /// Splits this size hint into the size hints of its first element and the remaining elements.
+    ///
+    /// The head size hint is [`SizeHint::exact(1)`](SizeHint::exact) if this size hint is definitely
+    /// non-empty, otherwise `(0, Some(1))`, or [`SizeHint::ZERO`] if definitely empty. The tail
+    /// size hint is [`Self::decrement`]. This models the `first, rest` shape of recursive adaptors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let (head, tail) = SizeHint::bounded(2, 5).split_first();
+    /// assert_eq!(head, SizeHint::exact(1));
+    /// assert_eq!(tail, SizeHint::bounded(1, 4));
+    ///
+    /// let (head, tail) = SizeHint::at_most(5).split_first();
+    /// assert_eq!(head, SizeHint::at_most(1));
+    /// assert_eq!(tail, SizeHint::at_most(4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn split_first(self) -> (Self, Self) {
+        let head = match (self.lower, self.upper) {
+            (1.., _) => Self::exact(1),
+            (0, Some(0)) => Self::ZERO,
+            (0, _) => Self::at_most(1),
+        };
+        (head, self.decrement())
+    }
+
+    /// Returns this size hint with both bounds divided by `divisor`, rounding down.
+    ///
+    /// This is the size hint of an adaptor that yields one element per complete group of
+    /// `divisor` elements, dropping any final partial group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(7, 10).div_floor(3), SizeHint::bounded(2, 3));
+    /// assert_eq!(SizeHint::unbounded(7).div_floor(3), SizeHint::unbounded(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn div_floor(self, divisor: usize) -> Self {
+        assert!(divisor != 0, "divisor should be non-zero");
+        match self.upper {
+            Some(upper) => Self { lower: self.lower / divisor, upper: Some(upper / divisor) },
+            None => Self { lower: self.lower / divisor, upper: None },
+        }
+    }
+
+    /// Returns whether splitting the elements described by this size hint into chunks of size
+    /// `chunk` would leave a final partial chunk.
+    ///
+    /// Returns [`None`] if this size hint is not exact, as this can not be determined, or if
+    /// `chunk` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::exact(10).has_partial_chunk(3), Some(true));
+    /// assert_eq!(SizeHint::exact(9).has_partial_chunk(3), Some(false));
+    /// assert_eq!(SizeHint::bounded(3, 10).has_partial_chunk(3), None);
+    /// assert_eq!(SizeHint::exact(9).has_partial_chunk(0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn has_partial_chunk(self, chunk: usize) -> Option<bool> {
+        match (self.exact_len(), chunk) {
+            (_, 0) | (None, _) => None,
+            (Some(len), chunk) => Some(len % chunk != 0),
+        }
+    }
+
+    /// Returns the capacity to preallocate for this size hint, according to `policy`.
+    ///
+    /// The returned capacity is never less than the lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, CapacityPolicy};
+    /// let hint = SizeHint::bounded(3, 10);
+    /// assert_eq!(hint.capacity(CapacityPolicy::Lower), 3);
+    /// assert_eq!(hint.capacity(CapacityPolicy::Upper), 10);
+    /// assert_eq!(hint.capacity(CapacityPolicy::Midpoint), 6);
+    /// assert_eq!(SizeHint::unbounded(5).capacity(CapacityPolicy::UpperOr(20)), 20);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn capacity(self, policy: CapacityPolicy) -> usize {
+        match (policy, self.upper) {
+            (CapacityPolicy::Upper | CapacityPolicy::UpperOr(_), Some(upper)) => upper,
+            (CapacityPolicy::Midpoint, Some(upper)) => self.lower + (upper - self.lower) / 2,
+            (CapacityPolicy::UpperOr(fallback), None) if fallback > self.lower => fallback,
+            _ => self.lower,
+        }
+    }
+
+    /// Returns the additional capacity to reserve for this size hint, given a buffer that already
+    /// has a capacity of `current_cap`, using the default [`CapacityPolicy::Lower`].
+    ///
+    /// This is the amount by which the reserve amount exceeds `current_cap`, or 0 if the buffer is
+    /// already large enough. This is useful when extending a partially filled buffer from a hinted
+    /// iterator. See [`Self::grow_step_with`] to use a different [`CapacityPolicy`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::exact(10);
+    /// assert_eq!(hint.grow_step(4), 6);
+    /// assert_eq!(hint.grow_step(12), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn grow_step(self, current_cap: usize) -> usize {
+        self.grow_step_with(current_cap, CapacityPolicy::Lower)
+    }
+
+    /// Returns the additional capacity to reserve for this size hint, according to `policy`, given
+    /// a buffer that already has a capacity of `current_cap`.
+    ///
+    /// This is the amount by which [`Self::capacity`] exceeds `current_cap`, or 0 if the buffer is
+    /// already large enough.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, CapacityPolicy};
+    /// let hint = SizeHint::bounded(3, 10);
+    /// assert_eq!(hint.grow_step_with(4, CapacityPolicy::Upper), 6);
+    /// assert_eq!(hint.grow_step_with(12, CapacityPolicy::Upper), 0);
+    /// assert_eq!(hint.grow_step_with(2, CapacityPolicy::Lower), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn grow_step_with(self, current_cap: usize, policy: CapacityPolicy) -> usize {
+        self.capacity(policy).saturating_sub(current_cap)
+    }
+
+    /// Returns the upper bound rounded up to the next power of two.
+    ///
+    /// This is useful for sizing power of two bucketed buffers.
+    ///
+    /// Returns [`None`] if the upper bound is unbounded, is 0, or if the next power of two would
+    /// overflow `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(3, 5).next_power_of_two_upper(), Some(8));
+    /// assert_eq!(SizeHint::exact(8).next_power_of_two_upper(), Some(8));
+    /// assert_eq!(SizeHint::unbounded(3).next_power_of_two_upper(), None);
+    /// assert_eq!(SizeHint::ZERO.next_power_of_two_upper(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next_power_of_two_upper(self) -> Option<usize> {
+        match self.upper {
+            Some(0) | None => None,
+            Some(upper) => upper.checked_next_power_of_two(),
+        }
+    }
+
+    /// Estimates how many reallocations a growable collection starting with `initial_cap` capacity
+    /// would need to hold up to the upper bound of this size hint.
+    ///
+    /// This assumes the collection doubles its capacity each time it grows, as [`Vec`] currently
+    /// does, and is intended as a diagnostic for capacity tuning rather than an exact count. An
+    /// `initial_cap` of 0 is treated as 1.
+    ///
+    /// Returns [`None`] if the upper bound is unbounded.
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::at_most(100).growth_reallocations(8), Some(4));
+    /// assert_eq!(SizeHint::at_most(8).growth_reallocations(8), Some(0));
+    /// assert_eq!(SizeHint::unbounded(8).growth_reallocations(8), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn growth_reallocations(self, initial_cap: usize) -> Option<u32> {
+        let Some(upper) = self.upper else { return None };
+        let mut capacity = if initial_cap == 0 { 1 } else { initial_cap };
+        let mut reallocations = 0;
+        while capacity < upper {
+            capacity = capacity.saturating_mul(2);
+            reallocations += 1;
+        }
+        Some(reallocations)
+    }
+
+    /// Returns `true` if this size hint range overlaps with another size hint range.
+    ///
+    /// Two ranges overlap if there exists at least one value that could be contained in both.
+    /// This operation is the negation of [`Self::disjoint`], and is also commutative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::overlaps(SizeHint::bounded(3, 6), SizeHint::bounded(5, 10)), "should overlap at 5 and 6");
+    /// assert!(SizeHint::overlaps(SizeHint::exact(5), SizeHint::UNIVERSAL), "Universal should overlap with any size hint");
+    /// assert!(!SizeHint::overlaps(SizeHint::unbounded(11), SizeHint::exact(5)), "should not overlap");
+    /// assert!(SizeHint::overlaps(SizeHint::unbounded(11), SizeHint::UNIVERSAL), "two unbounded hints should overlap");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn overlaps(self, other: Self) -> bool {
+        match (self.as_hint(), other.as_hint()) {
+            ((a_lower, Some(a_upper)), (b_lower, Some(b_upper))) => a_lower <= b_upper && b_lower <= a_upper,
+            ((_, Some(a_upper)), (b_lower, None)) => a_upper >= b_lower,
+            ((a_lower, None), (_, Some(b_upper))) => b_upper >= a_lower,
+            ((_, None), (_, None)) => true,
+        }
+    }
+
+    /// Returns the larger of the upper bounds of this size hint and `other`, treating an unbounded
+    /// upper bound as infinite.
+    ///
+    /// This is the safe choice when combining an optimistic and a conservative estimate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(2, 5).max_upper(SizeHint::bounded(3, 8)), Some(8));
+    /// assert_eq!(SizeHint::bounded(2, 5).max_upper(SizeHint::unbounded(3)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_upper(self, other: Self) -> Option<usize> {
+        match (self.upper, other.upper) {
+            (Some(a), Some(b)) if a > b => Some(a),
+            (Some(_), Some(b)) => Some(b),
+            (None, _) | (_, None) => None,
+        }
+    }
+
+    /// Returns the smaller of the upper bounds of this size hint and `other`, treating an unbounded
+    /// upper bound as infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(2, 5).min_upper(SizeHint::bounded(3, 8)), Some(5));
+    /// assert_eq!(SizeHint::bounded(2, 5).min_upper(SizeHint::unbounded(3)), Some(5));
+    /// assert_eq!(SizeHint::unbounded(2).min_upper(SizeHint::unbounded(3)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min_upper(self, other: Self) -> Option<usize> {
+        match (self.upper, other.upper) {
+            (Some(a), Some(b)) if a < b => Some(a),
+            (Some(_), Some(b)) => Some(b),
+            (upper, None) | (None, upper) => upper,
+        }
+    }
+
+    /// Returns `true` if this size hint's upper bound is strictly tighter (smaller) than `other`'s.
+    ///
+    /// An unbounded upper bound is the least tight. Together with [`Self::tighter_lower_than`],
+    /// this allows assembling the most informative size hint from several sources, bound by bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::bounded(2, 5).tighter_upper_than(SizeHint::bounded(2, 8)));
+    /// assert!(SizeHint::bounded(2, 5).tighter_upper_than(SizeHint::unbounded(2)));
+    /// assert!(!SizeHint::bounded(2, 5).tighter_upper_than(SizeHint::bounded(3, 5)), "equal is not tighter");
+    /// assert!(!SizeHint::unbounded(2).tighter_upper_than(SizeHint::unbounded(3)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn tighter_upper_than(self, other: Self) -> bool {
+        match (self.upper, other.upper) {
+            (Some(a), Some(b)) => a < b,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Returns `true` if this size hint's lower bound is strictly tighter (larger) than `other`'s.
+    ///
+    /// See also [`Self::tighter_upper_than`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::bounded(5, 8).tighter_lower_than(SizeHint::bounded(3, 8)));
+    /// assert!(!SizeHint::bounded(3, 8).tighter_lower_than(SizeHint::unbounded(3)), "equal is not tighter");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn tighter_lower_than(self, other: Self) -> bool {
+        self.lower > other.lower
+    }
+
+    /// Returns `true` if the lower bound of this size hint is `lower`, regardless of the upper
+    /// bound.
+    ///
+    /// This is useful for assertions that only pin down the lower bound of a size hint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::bounded(5, 10).lower_eq(5));
+    /// assert!(SizeHint::unbounded(5).lower_eq(5));
+    /// assert!(!SizeHint::exact(4).lower_eq(5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lower_eq(self, lower: usize) -> bool {
+        self.lower == lower
+    }
+
+    /// Returns the intersection of this size hint and `other`, or [`None`] if they are disjoint.
+    ///
+    /// The intersection is the tightest range contained in both size hints. Its lower bound is the
+    /// larger of the two lower bounds, and its upper bound is the smaller of the two upper bounds,
+    /// treating an unbounded upper bound as infinite. This is [`None`] exactly when
+    /// [`Self::overlaps`] is `false`. The operation is commutative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(3, 6).intersect(SizeHint::bounded(5, 10));
+    /// assert_eq!(hint, Some(SizeHint::bounded(5, 6)), "should be the overlapping range");
+    ///
+    /// let hint = SizeHint::unbounded(5).intersect(SizeHint::bounded(3, 10));
+    /// assert_eq!(hint, Some(SizeHint::bounded(5, 10)), "unbounded upper should be infinite");
+    ///
+    /// let hint = SizeHint::exact(5).intersect(SizeHint::unbounded(10));
+    /// assert_eq!(hint, None, "disjoint hints should have no intersection");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn intersect(self, other: Self) -> Option<Self> {
+        let lower = if self.lower > other.lower { self.lower } else { other.lower };
+        match Self::try_new(lower, self.min_upper(other)) {
+            Ok(hint) => Some(hint),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the intersection of this size hint and `other`, or [`SizeHint::ZERO`] if they are
+    /// disjoint.
+    ///
+    /// The intersection is the range of lengths that both size hints admit. If there is no such
+    /// length, this returns [`SizeHint::ZERO`], treating "no possible common length" as empty,
+    /// rather than failing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(3, 6).intersection_or_empty(SizeHint::bounded(5, 10));
+    /// assert_eq!(hint, SizeHint::bounded(5, 6), "should be the overlapping range");
+    ///
+    /// let hint = SizeHint::exact(5).intersection_or_empty(SizeHint::unbounded(10));
+    /// assert_eq!(hint, SizeHint::ZERO, "disjoint hints should be empty");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn intersection_or_empty(self, other: Self) -> Self {
+        match self.intersect(other) {
+            Some(hint) => hint,
+            None => Self::ZERO,
+        }
+    }
+
+    /// Combines the tightest bounds of this size hint and `other`, that is, the larger lower bound
+    /// and the smaller upper bound.
+    ///
+    /// This believes the most specific bound from each source, and is equivalent to the
+    /// intersection of the two size hints. See also [`Self::tighter_lower_than`] and
+    /// [`Self::tighter_upper_than`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the combined lower bound is greater than the combined upper
+    /// bound, meaning the two size hints contradict each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::bounded(3, 10).best_of(SizeHint::bounded(5, 12))?, SizeHint::bounded(5, 10));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::bounded(6, 10).best_of(SizeHint::at_most(4)).expect_err("hints should contradict");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn best_of(self, other: Self) -> Result<Self, InvalidSizeHint> {
+        match self.intersect(other) {
+            Some(hint) => Ok(hint),
+            None => Err(InvalidSizeHint),
+        }
+    }
+
+    /// Restricts this size hint to externally known limits, so that the lower bound is at least
+    /// `min` and the upper bound is at most `max`.
+    ///
+    /// The resulting lower bound is the larger of this lower bound and `min`, and the resulting
+    /// upper bound is the smaller of this upper bound and `max`, treating an unbounded upper bound
+    /// as `max`. This is useful when an iterator is known not to exceed some cap, even though its
+    /// own size hint is looser.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if the resulting lower bound is greater than the resulting upper
+    /// bound, that is, if this size hint lies entirely outside of `min..=max`, or `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// assert_eq!(SizeHint::unbounded(2).clamp(5, 10)?, SizeHint::bounded(5, 10));
+    /// assert_eq!(SizeHint::bounded(6, 8).clamp(5, 10)?, SizeHint::bounded(6, 8));
+    ///
+    /// let err: InvalidSizeHint = SizeHint::exact(12).clamp(5, 10).expect_err("hint should be outside the limits");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn clamp(self, min: usize, max: usize) -> Result<Self, InvalidSizeHint> {
+        let lower = if self.lower > min { self.lower } else { min };
+        let upper = match self.upper {
+            Some(upper) if upper < max => upper,
+            _ => max,
+        };
+        Self::try_bounded(lower, upper)
+    }
+
+    /// Combines this size hint with `other`, preferring an exact size hint when one is available.
+    ///
+    /// The result is determined by the following rules, in order:
+    /// - If one hint is exact and the other contains it, the exact hint is returned.
+    /// - If the hints overlap, their intersection is returned.
+    /// - Otherwise, the hints are contradictory and this size hint is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::exact(5).prefer_exact(SizeHint::bounded(3, 10));
+    /// assert_eq!(hint, SizeHint::exact(5), "should prefer the exact hint");
+    ///
+    /// let hint = SizeHint::bounded(3, 6).prefer_exact(SizeHint::bounded(5, 10));
+    /// assert_eq!(hint, SizeHint::bounded(5, 6), "should be the overlapping range");
+    ///
+    /// let hint = SizeHint::exact(5).prefer_exact(SizeHint::unbounded(10));
+    /// assert_eq!(hint, SizeHint::exact(5), "disjoint hints should return self");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn prefer_exact(self, other: Self) -> Self {
+        // an exact hint contained in the other is also their intersection
+        match self.intersect(other) {
+            Some(hint) => hint,
+            None => self,
+        }
+    }
+
+    /// Returns the smallest size hint containing both this size hint and `other`.
+    ///
+    /// The lower bound is the smaller of the two lower bounds, and the upper bound is the larger
+    /// of the two upper bounds, or unbounded if either is unbounded. Unlike [`Self::intersect`],
+    /// this never fails. The operation is commutative, and [`SizeHint::UNIVERSAL`] is an absorbing
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(3, 6).union(SizeHint::bounded(5, 10));
+    /// assert_eq!(hint, SizeHint::bounded(3, 10), "should span both ranges");
+    ///
+    /// let hint = SizeHint::exact(2).union(SizeHint::unbounded(10));
+    /// assert_eq!(hint, SizeHint::unbounded(2), "should be unbounded if either is unbounded");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        let lower = if self.lower < other.lower { self.lower } else { other.lower };
+        Self { lower, upper: self.max_upper(other) }
+    }
+
+    /// Returns the size hint of this size hint and `other` chained together, saturating at
+    /// `usize::MAX`.
+    ///
+    /// The lower bounds are added, and the upper bounds are added if both are present, otherwise
+    /// the result is unbounded. Overflow saturates at `usize::MAX` rather than panicking. This
+    /// backs the [`Add`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert_eq!(SizeHint::bounded(2, 3).saturating_add(SizeHint::bounded(4, 5)), SizeHint::bounded(6, 8));
+    /// assert_eq!(SizeHint::unbounded(2).saturating_add(SizeHint::bounded(4, 5)), SizeHint::unbounded(6));
+    /// assert_eq!(SizeHint::exact(usize::MAX).saturating_add(SizeHint::exact(1)), SizeHint::exact(usize::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        let upper = match (self.upper, other.upper) {
+            (Some(a), Some(b)) => Some(a.saturating_add(b)),
+            _ => None,
+        };
+        Self { lower: self.lower.saturating_add(other.lower), upper }
+    }
+
+    /// Returns the size hint of this size hint and `other` zipped together.
+    #[inline]
+    const fn zip(self, other: Self) -> Self {
+        let lower = if self.lower < other.lower { self.lower } else { other.lower };
+        let upper = match (self.upper, other.upper) {
+            (Some(a), Some(b)) if a < b => Some(a),
+            (Some(_), Some(b)) => Some(b),
+            (upper, None) | (None, upper) => upper,
+        };
+        Self { lower, upper }
+    }
+
+    /// Combines this size hint with `other` according to `op`.
+    ///
+    /// This is useful when the combination strategy is chosen at runtime.
+    ///
+    /// Returns [`None`] only if `op` is [`CombineOp::Intersection`] and the size hints are disjoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, CombineOp};
+    /// let (a, b) = (SizeHint::bounded(2, 6), SizeHint::bounded(4, 10));
+    /// assert_eq!(a.combine(b, CombineOp::Union), Some(SizeHint::bounded(2, 10)));
+    /// assert_eq!(a.combine(b, CombineOp::Intersection), Some(SizeHint::bounded(4, 6)));
+    /// assert_eq!(a.combine(b, CombineOp::Sum), Some(SizeHint::bounded(6, 16)));
+    /// assert_eq!(a.combine(b, CombineOp::Zip), Some(SizeHint::bounded(2, 6)));
+    ///
+    /// assert_eq!(SizeHint::exact(2).combine(SizeHint::exact(4), CombineOp::Intersection), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn combine(self, other: Self, op: CombineOp) -> Option<Self> {
+        match op {
+            CombineOp::Union => Some(self.union(other)),
+            CombineOp::Intersection => self.intersect(other),
+            CombineOp::Sum => Some(self.saturating_add(other)),
+            CombineOp::Zip => Some(self.zip(other)),
+        }
+    }
+
+    /// Folds a sequence of size hints into a single size hint, combining their bounds with
+    /// `lower_op` and `upper_op`.
+    ///
+    /// This is the most general way of reducing size hints, allowing any combination of the
+    /// bounds to be expressed, for example unions, intersections, or sums. The first size hint is
+    /// used as the initial value, and each subsequent size hint is combined with the accumulated
+    /// bounds, with the result re-validated at each step. Returns [`None`] if `hints` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if combining the bounds produces an invalid size hint, that is, if the combined
+    /// lower bound is greater than the combined upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hints = [SizeHint::bounded(2, 6), SizeHint::bounded(4, 10), SizeHint::exact(3)];
+    /// let union = SizeHint::fold_bounds(hints, usize::min, |a, b| a.zip(b).map(|(a, b)| a.max(b)));
+    /// assert_eq!(union, Some(SizeHint::bounded(2, 10)));
+    ///
+    /// assert_eq!(SizeHint::fold_bounds([], usize::min, |a, b| a.max(b)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fold_bounds<I: IntoIterator<Item = Self>>(
+        hints: I,
+        lower_op: impl Fn(usize, usize) -> usize,
+        upper_op: impl Fn(Option<usize>, Option<usize>) -> Option<usize>,
+    ) -> Option<Self> {
+        hints
+            .into_iter()
+            .reduce(|acc, hint| Self::new(lower_op(acc.lower, hint.lower), upper_op(acc.upper, hint.upper)))
+    }
+
+    /// Returns `true` if this size hint range is disjoint with another range.
+    ///
+    /// Two ranges are disjoint if there exists no value that could be contained in both.
+    /// This operation is the negation of [`Self::overlaps`], and is also commutative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::disjoint(SizeHint::exact(5), SizeHint::unbounded(10)), "should be disjoint");
+    /// assert!(SizeHint::disjoint(SizeHint::exact(5), SizeHint::bounded(6, 10)), "should be disjoint");
+    /// assert!(!SizeHint::disjoint(SizeHint::unbounded(11), SizeHint::UNIVERSAL), "two unbounded hints should never be disjoint");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn disjoint(self, other: Self) -> bool {
+        !Self::overlaps(self, other)
+    }
+
+    /// Returns `true` if this size hint admits `len` as a possible length, that is, if `len` is
+    /// within its bounds.
+    ///
+    /// This is useful for validating an externally observed or claimed length against a size hint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(3, 6);
+    /// assert!(hint.admits(3), "should admit the lower bound");
+    /// assert!(hint.admits(6), "should admit the upper bound");
+    /// assert!(!hint.admits(7), "should not admit a length past the upper bound");
+    /// assert!(SizeHint::unbounded(3).admits(usize::MAX), "unbounded should admit any larger length");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn admits(self, len: usize) -> bool {
+        match self.upper {
+            Some(upper) => self.lower <= len && len <= upper,
+            None => self.lower <= len,
+        }
+    }
+
+    /// Returns `true` if this size hint range is completely contained within another range.
+    ///
+    /// This operation is not commutative, i.e. `a.subset_of(b)` does not imply `b.subset_of(a)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// assert!(SizeHint::bounded(4, 6).subset_of(SizeHint::bounded(4, 6)), "should be a subset (equal ranges)");
+    /// assert!(SizeHint::bounded(4, 6).subset_of(SizeHint::bounded(3, 9)), "should be a subset");
+    /// assert!(!SizeHint::bounded(3, 9).subset_of(SizeHint::bounded(4, 6)), "should not be a subset (not commutative)");
+    /// assert!(!SizeHint::bounded(2, 6).subset_of(SizeHint::bounded(3, 6)), "should not be a subset (lower bound)");
+    /// assert!(!SizeHint::bounded(4, 7).subset_of(SizeHint::bounded(3, 6)), "should not be a subset (upper bound)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subset_of(self, other: Self) -> bool {
+        match (self.as_hint(), other.as_hint()) {
+            ((a_low, Some(a_up)), (b_low, Some(b_up))) => a_low >= b_low && a_up <= b_up,
+            ((a_low, _), (b_low, None)) => a_low >= b_low,
+            ((_, None), (_, Some(_))) => false,
+        }
+    }
+
+    /// Returns `true` if `range` is completely contained within this size hint range.
+    ///
+    /// This is equivalent to [`Self::subset_of`] with the operands reversed, but accepts any
+    /// [`RangeBounds`]. An empty range is contained within any size hint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::SizeHint;
+    /// let hint = SizeHint::bounded(2, 10);
+    /// assert!(hint.contains_range(3..=7), "should contain the range");
+    /// assert!(hint.contains_range(2..11), "should contain the range (exclusive end)");
+    /// assert!(!hint.contains_range(1..5), "should not contain the range (lower bound)");
+    /// assert!(!hint.contains_range(5..), "should not contain the range (unbounded)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_range<R: RangeBounds<usize>>(self, range: R) -> bool {
+        match Self::from_range_bounds(range) {
+            Ok(range) => range.subset_of(self),
+            Err(InvalidSizeHint) => true,
+        }
+    }
+}
+
+impl TryFrom<(usize, Option<usize>)> for SizeHint {
+    type Error = InvalidSizeHint;
+
+    #[inline]
+    fn try_from(hint: (usize, Option<usize>)) -> Result<Self, Self::Error> {
         match hint {
             (lower, Some(upper)) => Self::try_bounded(lower, upper),
             (lower, None) => Ok(Self::unbounded(lower)),
@@ -329,6 +1796,37 @@ impl From<SizeHint> for (usize, Option<usize>) {
     }
 }
 
+/// Converts a bounded size hint into the half-open [`Range`] of candidate lengths,
+/// `lower..upper + 1`.
+///
+/// # Errors
+///
+/// Returns [`InvalidSizeHint`] if the size hint is unbounded, or if its upper bound is
+/// `usize::MAX`, as the exclusive end would overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::{SizeHint, InvalidSizeHint};
+/// # use core::ops::Range;
+/// # fn main() -> Result<(), InvalidSizeHint> {
+/// assert_eq!(Range::try_from(SizeHint::bounded(3, 7))?, 3..8);
+///
+/// let err: InvalidSizeHint = Range::try_from(SizeHint::unbounded(3)).expect_err("should be unbounded");
+/// let err: InvalidSizeHint = Range::try_from(SizeHint::at_most(usize::MAX)).expect_err("end should overflow");
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<SizeHint> for Range<usize> {
+    type Error = InvalidSizeHint;
+
+    #[inline]
+    fn try_from(hint: SizeHint) -> Result<Self, Self::Error> {
+        let end = hint.upper.and_then(|upper| upper.checked_add(1)).ok_or(InvalidSizeHint)?;
+        Ok(hint.lower..end)
+    }
+}
+
 impl TryFrom<Range<usize>> for SizeHint {
     type Error = InvalidSizeHint;
 
@@ -367,8 +1865,7 @@ impl TryFrom<RangeTo<usize>> for SizeHint {
 
     #[inline]
     fn try_from(range: RangeTo<usize>) -> Result<Self, Self::Error> {
-        let end = range.end.checked_sub(1).ok_or(InvalidSizeHint)?;
-        Self::try_bounded(0, end)
+        Self::from_range_to(range.end)
     }
 }
 
@@ -379,6 +1876,22 @@ impl From<RangeToInclusive<usize>> for SizeHint {
     }
 }
 
+/// Creates an exact size hint from the length of a slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHint;
+/// let items = vec![1, 2, 3];
+/// assert_eq!(SizeHint::from(&items[..]), SizeHint::exact(3));
+/// ```
+impl<T> From<&[T]> for SizeHint {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::exact(slice.len())
+    }
+}
+
 /// A [`SizeHint`] represents a range of possible iterator lengths.
 impl RangeBounds<usize> for SizeHint {
     /// Returns the smallest possible iterator length. Always [`Bound::Included`].
@@ -405,3 +1918,97 @@ impl PartialEq<SizeHint> for (usize, Option<usize>) {
         self.0 == other.lower && self.1 == other.upper
     }
 }
+
+/// Adds two size hints, as for chained iterators, saturating at `usize::MAX`.
+///
+/// See [`SizeHint::saturating_add`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHint;
+/// assert_eq!(SizeHint::bounded(2, 3) + SizeHint::bounded(4, 5), SizeHint::bounded(6, 8));
+/// assert_eq!(SizeHint::unbounded(2) + SizeHint::bounded(4, 5), SizeHint::unbounded(6));
+/// ```
+impl Add for SizeHint {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+}
+
+/// Multiplies both bounds of a size hint by a factor, as for a `flat_map` over fixed size inner
+/// iterators, saturating at `usize::MAX`.
+///
+/// See [`SizeHint::scale`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHint;
+/// assert_eq!(SizeHint::bounded(2, 5) * 3, SizeHint::bounded(6, 15));
+/// assert_eq!(SizeHint::unbounded(2) * 0, SizeHint::ZERO);
+/// ```
+impl Mul<usize> for SizeHint {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, factor: usize) -> Self {
+        self.scale(factor)
+    }
+}
+
+/// Formats the size hint in range notation.
+///
+/// An exact size hint is formatted as its length (`5`), a bounded size hint as an inclusive range
+/// (`3..=10`), and an unbounded size hint as a range from its lower bound (`5..`). A universal size
+/// hint is formatted as a full range (`..`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHint;
+/// assert_eq!(SizeHint::exact(5).to_string(), "5");
+/// assert_eq!(SizeHint::bounded(3, 10).to_string(), "3..=10");
+/// assert_eq!(SizeHint::unbounded(5).to_string(), "5..");
+/// assert_eq!(SizeHint::UNIVERSAL.to_string(), "..");
+/// ```
+impl Display for SizeHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.as_hint() {
+            (lower, Some(upper)) if lower == upper => write!(f, "{lower}"),
+            (lower, Some(upper)) => write!(f, "{lower}..={upper}"),
+            (0, None) => write!(f, ".."),
+            (lower, None) => write!(f, "{lower}.."),
+        }
+    }
+}
+
+/// Deserializes a size hint from a struct with `lower` and `upper` fields, failing if
+/// `lower > upper`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHint;
+/// let hint: SizeHint = serde_json::from_str(r#"{"lower":3,"upper":10}"#).expect("hint should be valid");
+/// assert_eq!(hint, SizeHint::bounded(3, 10));
+///
+/// serde_json::from_str::<SizeHint>(r#"{"lower":10,"upper":5}"#).expect_err("hint should be invalid");
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SizeHint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "SizeHint")]
+        struct Unvalidated {
+            lower: usize,
+            upper: Option<usize>,
+        }
+
+        let Unvalidated { lower, upper } = Unvalidated::deserialize(deserializer)?;
+        Self::try_new(lower, upper).map_err(serde::de::Error::custom)
+    }
+}
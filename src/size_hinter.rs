@@ -1,6 +1,9 @@
 use core::iter::FusedIterator;
 
-use crate::HintSize;
+use crate::{
+    CapacityPolicy, DedupHint, DelayedHint, DiscoverLen, HintSize, OnExhaust, PreferInner, SizeHint, StatefulHint,
+    Validated,
+};
 
 #[cfg(doc)]
 use crate::*;
@@ -75,6 +78,96 @@ pub trait SizeHinter: Iterator + Sized {
         HintSize::min(self, lower)
     }
 
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on `upper`
+    /// only, that is, [`SizeHint::at_most`].
+    ///
+    /// It is the caller's responsibility to ensure that `upper` is an accurate upper bound for the
+    /// number of elements remaining in this iterator. See [`Self::hint_upper`] to preserve this
+    /// iterator's lower bound instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upper` is less than the lower bound of this [`Iterator::size_hint`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).hint_max(6);
+    ///
+    /// assert_eq!(iter.size_hint(), (0, Some(6)), "Should match initial upper bound");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (0, Some(5)), "Should reflect new upper bound");
+    /// ```
+    #[inline]
+    fn hint_max(self, upper: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::max(self, upper)
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on this
+    /// [`Iterator::size_hint`]'s lower bound and `upper`.
+    ///
+    /// This preserves the lower bound reported by this iterator, while imposing a tighter upper
+    /// bound. It is the caller's responsibility to ensure that `upper` is an accurate upper bound
+    /// for the number of elements remaining in this iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upper` is less than the lower bound of this [`Iterator::size_hint`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..10).hint_upper(12);
+    ///
+    /// assert_eq!(iter.size_hint(), (9, Some(12)), "Should preserve the wrapped lower bound");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (8, Some(11)), "Should reflect new state");
+    /// ```
+    #[inline]
+    fn hint_upper(self, upper: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        let lower = self.size_hint().0;
+        HintSize::new(self, lower, upper)
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint::zero_or_one`].
+    ///
+    /// This is useful for custom iterators that yield at most a single element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - this [`Iterator::size_hint`] is invalid
+    /// - this [`Iterator::size_hint`]'s lower bound is greater than 1
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..10).filter(|&x| x == 5).hint_optional();
+    ///
+    /// assert_eq!(iter.size_hint(), (0, Some(1)), "Should match zero or one size hint");
+    /// assert_eq!(iter.next(), Some(5), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (0, Some(0)), "Should reflect new state");
+    /// ```
+    #[inline]
+    fn hint_optional(self) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::try_new_impl(self, SizeHint::zero_or_one()).expect("Invalid size hint")
+    }
+
     /// Tries to wrap this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on
     /// `lower` and `upper`.
     ///
@@ -114,6 +207,146 @@ pub trait SizeHinter: Iterator + Sized {
         HintSize::try_new(self, lower, upper)
     }
 
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] described by
+    /// `range`.
+    ///
+    /// `range` may be any [`RangeBounds<usize>`](core::ops::RangeBounds), for example `3..=10`,
+    /// `3..11`, or `5..`, and is converted as by [`SizeHint::from_range_bounds`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - this [`Iterator::size_hint`] is invalid
+    /// - `range` is empty, for example `3..3` or `..0`
+    /// - `range` does not overlap with this [`Iterator::size_hint`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).hint_range(2..=6);
+    ///
+    /// assert_eq!(iter.size_hint(), (2, Some(6)), "Should match initial size hint");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (1, Some(5)), "Should reflect new state");
+    ///
+    /// assert_eq!((1..5).hint_range(2..).size_hint(), (2, None), "Should support unbounded ranges");
+    /// ```
+    #[inline]
+    fn hint_range<R: core::ops::RangeBounds<usize>>(self, range: R) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        self.try_hint_range(range).expect("Invalid size hint")
+    }
+
+    /// Tries to wrap this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`]
+    /// described by `range`.
+    ///
+    /// See [`Self::hint_range`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if:
+    /// - `range` is empty, for example `3..3` or `..0`
+    /// - `range` does not overlap with this [`Iterator::size_hint`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = (1..5).try_hint_range(2..7)?;
+    /// assert_eq!(iter.size_hint(), (2, Some(6)), "Should match initial size hint");
+    ///
+    /// let err: InvalidSizeHint = (1..5).try_hint_range(3..3).expect_err("range should be empty");
+    /// let err: InvalidSizeHint = (1..5).try_hint_range(5..).expect_err("range should not overlap");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn try_hint_range<R: core::ops::RangeBounds<usize>>(
+        self,
+        range: R,
+    ) -> Result<HintSize<Self>, crate::InvalidSizeHint>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::try_new_impl(self, SizeHint::from_range_bounds(range)?)
+    }
+
+    /// Wraps this [`Iterator`] in a [`HintSize`] that produces an exact [`SizeHint`] of `len`.
+    ///
+    /// Unlike [`Self::exact_len`], the resulting adaptor does not implement
+    /// [`ExactSizeIterator`]. This is useful when the exact number of elements is known, but the
+    /// [`ExactSizeIterator`] bound is unwanted. Prefer [`Self::exact_len`] otherwise.
+    ///
+    /// It is the caller's responsibility to ensure that `len` is the exact number of elements
+    /// remaining in this iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - this [`Iterator::size_hint`] is invalid
+    /// - `len` is not within this [`Iterator::size_hint`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).filter(|x| x % 2 == 0).hint_exact(2);
+    ///
+    /// assert_eq!(iter.size_hint(), (2, Some(2)), "Should match initial exact size hint");
+    /// assert_eq!(iter.next(), Some(2), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (1, Some(1)), "Should reflect new state");
+    /// ```
+    #[inline]
+    fn hint_exact(self, len: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        self.try_hint_exact(len).expect("Invalid size hint")
+    }
+
+    /// Tries to wrap this [`Iterator`] in a [`HintSize`] that produces an exact [`SizeHint`] of
+    /// `len`.
+    ///
+    /// See [`Self::hint_exact`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `len` is not within this [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = (1..5).filter(|x| x % 2 == 0).try_hint_exact(2)?;
+    /// assert_eq!(iter.size_hint(), (2, Some(2)), "Should match initial exact size hint");
+    ///
+    /// let err: InvalidSizeHint = (1..5).try_hint_exact(5).expect_err("len should not be within the size hint");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn try_hint_exact(self, len: usize) -> Result<HintSize<Self>, crate::InvalidSizeHint>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::try_new_impl(self, SizeHint::exact(len))
+    }
+
     /// Tries to wrap this [`Iterator`] in a [`HintSize`] that produces a [`SizeHint`] based on `lower`.
     ///
     /// See [`Self::hint_min`] for more details.
@@ -147,6 +380,294 @@ pub trait SizeHinter: Iterator + Sized {
         HintSize::try_min(self, lower)
     }
 
+    /// Tries to wrap this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on
+    /// `upper` only, that is, [`SizeHint::at_most`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `upper` is less than the lower bound of this
+    /// [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped iterator's [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let mut iter = (1..5).try_hint_max(6)?;
+    /// assert_eq!(iter.size_hint(), (0, Some(6)), "Should match initial upper bound");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (0, Some(5)), "Should reflect new upper bound");
+    ///
+    /// let err: InvalidSizeHint = (10..20).try_hint_max(5)
+    ///     .expect_err("Upper bound should not be less than lower bound");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn try_hint_max(self, upper: usize) -> Result<HintSize<Self>, crate::InvalidSizeHint>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::try_max(self, upper)
+    }
+
+    /// Tries to wrap this [`FusedIterator`] in a [`HintSize`] that produces the intersection of
+    /// this [`Iterator::size_hint`] and `hint`.
+    ///
+    /// Unlike [`Self::hint_size`], this never loosens the size hint. The resulting [`SizeHint`]
+    /// is always a subset of both this [`Iterator::size_hint`] and `hint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if `hint` is disjoint with this [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, SizeHint, InvalidSizeHint};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let iter = (1..10).filter(|x| x % 2 == 0).tighten(SizeHint::at_most(5))?;
+    /// assert_eq!(iter.size_hint(), (0, Some(5)), "Should tighten the upper bound");
+    ///
+    /// let iter = (1..10).filter(|x| x % 2 == 0).tighten(SizeHint::unbounded(2))?;
+    /// assert_eq!(iter.size_hint(), (2, Some(9)), "Should tighten the lower bound");
+    ///
+    /// let err: InvalidSizeHint = (1..10).tighten(SizeHint::at_most(5)).expect_err("Hints should be disjoint");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn tighten(self, hint: impl Into<SizeHint>) -> Result<HintSize<Self>, crate::InvalidSizeHint>
+    where
+        Self: FusedIterator,
+    {
+        let wrapped: SizeHint = self.size_hint().try_into().expect("iterator's size hint should be valid");
+        let hint = wrapped.intersect(hint.into()).ok_or(crate::InvalidSizeHint)?;
+        HintSize::try_new_impl(self, hint)
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on
+    /// `lower` and `upper`, clamped to this [`Iterator::size_hint`].
+    ///
+    /// Unlike [`Self::hint_size`], this never fails. The requested size hint is intersected with
+    /// this [`Iterator::size_hint`]. If the requested size hint is invalid or disjoint with this
+    /// [`Iterator::size_hint`], this [`Iterator::size_hint`] is used instead. This is useful for
+    /// best-effort hinting from imprecise estimates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let iter = (1..10).filter(|x| x % 2 == 0).hint_size_clamped(2, 20);
+    /// assert_eq!(iter.size_hint(), (2, Some(9)), "Should clamp to the wrapped upper bound");
+    ///
+    /// let iter = (1..5).hint_size_clamped(6, 10);
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Should fall back to the wrapped size hint");
+    /// ```
+    #[inline]
+    fn hint_size_clamped(self, lower: usize, upper: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        let wrapped: SizeHint = self.size_hint().try_into().expect("iterator's size hint should be valid");
+        let hint = SizeHint::try_bounded(lower, upper).ok().and_then(|hint| hint.intersect(wrapped)).unwrap_or(wrapped);
+        HintSize::try_new_impl(self, hint).expect("clamped size hint should be valid")
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on
+    /// `lower` and `upper` if they are valid, or this [`Iterator::size_hint`] otherwise.
+    ///
+    /// Unlike [`Self::hint_size_clamped`], a valid requested size hint is applied unchanged, rather
+    /// than intersected with this [`Iterator::size_hint`]. If the requested size hint is invalid or
+    /// disjoint with this [`Iterator::size_hint`], this iterator's own size hint is used instead, so
+    /// the adaptor behaves as if the hint was never applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let iter = (1..10).filter(|x| x % 2 == 0).hint_or_identity(2, 20);
+    /// assert_eq!(iter.size_hint(), (2, Some(20)), "Should apply a valid size hint unchanged");
+    ///
+    /// let iter = (1..5).hint_or_identity(6, 10);
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Should fall back to the wrapped size hint");
+    /// ```
+    #[inline]
+    fn hint_or_identity(self, lower: usize, upper: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        let wrapped: SizeHint = self.size_hint().try_into().expect("iterator's size hint should be valid");
+        let hint = match SizeHint::try_bounded(lower, upper) {
+            Ok(hint) if hint.overlaps(wrapped) => hint,
+            _ => wrapped,
+        };
+        HintSize::try_new_impl(self, hint).expect("size hint should be valid")
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces a [`SizeHint`] based on
+    /// `lower` and a statistical `estimate` of the upper bound.
+    ///
+    /// This is intended for cases where the number of elements has been sampled, and `estimate`
+    /// is a high-percentile guess at the length rather than a hard bound. It is otherwise
+    /// identical to [`Self::hint_size`].
+    ///
+    /// **Warning:** an upper bound that is exceeded violates the [`Iterator::size_hint`] contract,
+    /// and may cause incorrect behavior or panics in code that relies on it. Only use this when
+    /// consumers treat the upper bound as advisory, such as when reserving capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `lower > estimate`
+    /// - `estimate` is less than this [`Iterator::size_hint`]'s lower bound
+    /// - `lower` is greater than this [`Iterator::size_hint`]'s upper bound (if present)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// // the 95th percentile of sampled lengths was 8
+    /// let mut iter = (1..5).hint_estimate(0, 8);
+    ///
+    /// assert_eq!(iter.size_hint(), (0, Some(8)), "Should match estimated size hint");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (0, Some(7)), "Should reflect new state");
+    /// ```
+    #[inline]
+    fn hint_estimate(self, lower: usize, estimate: usize) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        HintSize::new(self, lower, estimate)
+    }
+
+    /// Wraps this [`FusedIterator`] in a [`HintSize`] that produces the [`SizeHint`] computed by
+    /// applying `f` to this iterator.
+    ///
+    /// This is useful when the size hint depends on the state of the iterator at the time it is
+    /// wrapped. It is otherwise identical to [`Self::hint_size`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the computed [`SizeHint`] does not overlap with this [`Iterator::size_hint`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::{SizeHint, SizeHinter};
+    ///
+    /// let mut iter = (1..5).hint_from(|iter| SizeHint::bounded(iter.len(), iter.len() * 2));
+    ///
+    /// assert_eq!(iter.size_hint(), (4, Some(8)), "Should match computed size hint");
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (3, Some(7)), "Should reflect new state");
+    /// ```
+    #[inline]
+    fn hint_from<F: FnOnce(&Self) -> SizeHint>(self, f: F) -> HintSize<Self>
+    where
+        Self: FusedIterator,
+    {
+        let hint = f(&self);
+        HintSize::try_new_impl(self, hint).expect("Invalid size hint")
+    }
+
+    /// Eagerly reads up to `sample` elements of this [`FusedIterator`] into a buffer, and wraps the
+    /// buffer chained with the rest of this iterator in a [`HintSize`] with a refined [`SizeHint`].
+    ///
+    /// The refined size hint is the number of buffered elements plus this [`Iterator::size_hint`]
+    /// observed after reading. This trades a small eager read for a tighter size hint, for
+    /// iterators whose size hint narrows as they are advanced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid after reading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let evens = (1..=10).filter(|x| x % 2 == 0);
+    /// assert_eq!(evens.size_hint(), (0, Some(10)));
+    ///
+    /// let iter = evens.refine_hint(2);
+    /// assert_eq!(iter.size_hint(), (2, Some(8)), "Should count the buffered elements exactly");
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4, 6, 8, 10], "Should yield all elements in order");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn refine_hint(self, sample: usize) -> HintSize<core::iter::Chain<alloc::vec::IntoIter<Self::Item>, Self>>
+    where
+        Self: FusedIterator,
+    {
+        let mut iter = self;
+        let buffer: alloc::vec::Vec<_> = iter.by_ref().take(sample).collect();
+        let remaining: SizeHint = iter.size_hint().try_into().expect("iterator's size hint should be valid");
+        let hint = SizeHint::exact(buffer.len()).saturating_add(remaining);
+        HintSize::try_new_impl(buffer.into_iter().chain(iter), hint).expect("refined size hint should be valid")
+    }
+
+    /// Tries to wrap this [`FusedIterator`] in a [`HintSize`] that produces the current
+    /// [`Iterator::size_hint`] of `other`.
+    ///
+    /// This is useful for propagating a known length across iterators known to be the same
+    /// length, such as iterators over parallel arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidSizeHint`] if `other`'s [`Iterator::size_hint`] is invalid, or if it does
+    /// not overlap with this [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::{InvalidSizeHint, SizeHinter};
+    ///
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let names = ["a", "b", "c"];
+    /// let values = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut evens = values.iter().filter(|&&x| x % 2 == 0).hint_like(&names.iter())?;
+    /// assert_eq!(evens.size_hint(), (3, Some(3)), "Should match the other iterator's size hint");
+    ///
+    /// assert_eq!(evens.next(), Some(&2), "Should not change underlying iterator");
+    /// assert_eq!(evens.size_hint(), (2, Some(2)), "Should reflect new state");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn hint_like<J: Iterator>(self, other: &J) -> Result<HintSize<Self>, crate::InvalidSizeHint>
+    where
+        Self: FusedIterator,
+    {
+        let hint = SizeHint::try_from(other.size_hint())?;
+        HintSize::try_new_impl(self, hint)
+    }
+
     /// Wraps this [`Iterator`] in a [`HintSize`] that produces a [`SizeHint::UNIVERSAL`].
     ///
     /// This implementation, and the [`SizeHint::UNIVERSAL`] it returns, is always correct,
@@ -212,6 +733,37 @@ pub trait SizeHinter: Iterator + Sized {
         crate::ExactLen::new(self, len)
     }
 
+    /// Wraps this [`FusedIterator`] in a [`ExactLen`] of length `expected`, asserting that this
+    /// iterator yields exactly `expected` elements.
+    ///
+    /// This is [`Self::exact_len`], named for use in tests. `expected` is validated against this
+    /// [`Iterator::size_hint`] on construction and, in debug builds, against the number of
+    /// elements actually yielded as the adaptor is iterated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - the wrapped [`Iterator::size_hint`] is invalid
+    /// - `expected` is not within the wrapped [`Iterator::size_hint`]
+    /// - in debug builds, if the wrapped iterator yields more or fewer than `expected` elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let evens = (1..5).filter(|x| x % 2 == 0).assert_exact_len(2);
+    /// assert_eq!(evens.collect::<Vec<_>>(), vec![2, 4], "Should yield exactly 2 elements");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn assert_exact_len(self, expected: usize) -> crate::ExactLen<Self>
+    where
+        Self: FusedIterator,
+    {
+        self.exact_len(expected)
+    }
+
     /// Tries to wrap this [`FusedIterator`] in a [`ExactLen`] that provides [`ExactSizeIterator::len`]
     /// based on `len`.
     ///
@@ -246,6 +798,315 @@ pub trait SizeHinter: Iterator + Sized {
     {
         crate::ExactLen::try_new(self, len)
     }
+
+    /// Wraps this [`Iterator`] in a [`DiscoverLen`] that counts the elements yielded, and reports
+    /// the discovered length once this iterator is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).filter(|x| x % 2 == 0).discover_len();
+    /// assert_eq!(iter.discovered(), None, "Length should not be known until exhausted");
+    ///
+    /// assert_eq!(iter.by_ref().count(), 2, "Should not change underlying iterator");
+    /// assert_eq!(iter.discovered(), Some(2), "Length should be known once exhausted");
+    /// ```
+    #[inline]
+    fn discover_len(self) -> DiscoverLen<Self> {
+        DiscoverLen::new(self)
+    }
+
+    /// Wraps this [`Iterator`] in a [`SharedLen`] that publishes the lower bound of this
+    /// [`Iterator::size_hint`] to a shared [`AtomicUsize`] as it is advanced.
+    ///
+    /// Returns the adaptor along with a handle to the shared value, which can be read from another
+    /// thread without borrowing the iterator, for example to report progress.
+    ///
+    /// [`AtomicUsize`]: core::sync::atomic::AtomicUsize
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::sync::atomic::Ordering;
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let (mut iter, remaining) = (1..5).shared_len();
+    /// assert_eq!(remaining.load(Ordering::Relaxed), 4, "Should match initial lower bound");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(remaining.load(Ordering::Relaxed), 3, "Should reflect new state");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn shared_len(self) -> (crate::SharedLen<Self>, alloc::sync::Arc<core::sync::atomic::AtomicUsize>) {
+        let iter = crate::SharedLen::new(self);
+        let handle = iter.handle();
+        (iter, handle)
+    }
+
+    /// Wraps this [`Iterator`] in a [`DedupHint`] that removes consecutive duplicate elements,
+    /// and reports a size hint consistent with that behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let iter = [1, 1, 2, 2, 3].into_iter().dedup_hint();
+    /// assert_eq!(iter.size_hint(), (1, Some(5)), "Any number of elements may be duplicates");
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3], "Should skip consecutive duplicates");
+    /// ```
+    #[inline]
+    fn dedup_hint(self) -> DedupHint<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        DedupHint::new(self)
+    }
+
+    /// Wraps this [`Iterator`] in an [`OnExhaust`] that runs `f` exactly once, the first time this
+    /// iterator returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::cell::Cell;
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let fired = Cell::new(false);
+    /// let mut iter = (1..3).on_exhaust(|| fired.set(true));
+    ///
+    /// assert_eq!(iter.by_ref().count(), 2, "Should not change underlying iterator");
+    /// assert!(fired.get(), "Callback should fire on exhaustion");
+    /// ```
+    #[inline]
+    fn on_exhaust<F: FnOnce()>(self, f: F) -> OnExhaust<Self, F> {
+        OnExhaust::new(self, f)
+    }
+
+    /// Wraps this [`Iterator`] in a [`WindowsHint`] that yields overlapping windows of `window`
+    /// consecutive elements, reporting the number of windows remaining as its size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().windows_hint(2);
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should report the number of windows");
+    ///
+    /// assert_eq!(iter.next(), Some(vec![1, 2]), "Should yield the first window");
+    /// assert_eq!(iter.size_hint(), (2, Some(2)), "Should reflect the remaining windows");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[track_caller]
+    fn windows_hint(self, window: usize) -> crate::WindowsHint<Self> {
+        crate::WindowsHint::new(self, window)
+    }
+
+    /// Wraps this [`Iterator`] in a [`DelayedHint`] that reports [`SizeHint::UNIVERSAL`] until its
+    /// first element is consumed, and `real` (decremented for each element consumed) afterwards.
+    ///
+    /// This models iterators whose size only becomes known after iteration has started, and is
+    /// useful for testing consumers that cache the initial [`Iterator::size_hint`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - this [`Iterator::size_hint`] is invalid
+    /// - `real` does not overlap with this [`Iterator::size_hint`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::{SizeHint, SizeHinter};
+    ///
+    /// let mut iter = (1..5).delayed_hint(SizeHint::exact(4));
+    /// assert_eq!(iter.size_hint(), (0, None), "Should be universal before the first element");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should report the real size hint");
+    /// ```
+    #[inline]
+    fn delayed_hint(self, real: SizeHint) -> DelayedHint<Self>
+    where
+        Self: FusedIterator,
+    {
+        DelayedHint::new(self, real)
+    }
+
+    /// Wraps this [`Iterator`] in a [`StatefulHint`] whose size hint starts at `initial`, and is
+    /// recomputed by applying `update` to the current hint after each element.
+    ///
+    /// This is useful when each element consumed changes the remaining count non-uniformly, such
+    /// as when modelling a run-length decoder. It is the caller's responsibility to ensure that
+    /// the resulting size hints are accurate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::{SizeHint, SizeHinter};
+    ///
+    /// let mut iter = (1..4).hint_each(SizeHint::exact(6), |hint| hint.saturating_sub(2));
+    /// assert_eq!(iter.size_hint(), (6, Some(6)), "Should start at the initial hint");
+    ///
+    /// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Should apply update after each element");
+    /// ```
+    #[inline]
+    fn hint_each<F: FnMut(SizeHint) -> SizeHint>(self, initial: SizeHint, update: F) -> StatefulHint<Self, F> {
+        StatefulHint::new(self, initial, update)
+    }
+
+    /// Wraps this [`Iterator`] in a [`LogHint`] that logs this [`Iterator::size_hint`] to `target`
+    /// via [`log::trace!`] whenever it is read and has changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).log_hint("my_iter");
+    /// iter.next();
+    /// assert_eq!(iter.size_hint(), (3, Some(3)), "Should not change underlying size hint");
+    /// ```
+    #[cfg(feature = "log")]
+    #[inline]
+    fn log_hint(self, target: &'static str) -> crate::LogHint<Self> {
+        crate::LogHint::new(self, target)
+    }
+
+    /// Collects this [`Iterator`] into a new collection `C`, returning the collection and the
+    /// capacity suggested by this [`Iterator::size_hint`].
+    ///
+    /// The suggested capacity is the [`CapacityPolicy::Lower`] capacity of this iterator's initial
+    /// [`SizeHint`]. This method does not reserve any capacity itself, how much `C` reserves is left
+    /// to its [`Extend`] implementation. Comparing the suggested capacity against the final length
+    /// of the collection is useful for measuring the accuracy of a size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let (odds, suggested): (Vec<i32>, usize) = (1..=5).filter(|x| x % 2 == 1).collect_into_hinted();
+    /// assert_eq!(odds, vec![1, 3, 5], "Should collect all elements");
+    /// assert_eq!(suggested, 0, "Filter's lower bound is 0");
+    ///
+    /// let (odds, suggested): (Vec<i32>, usize) = (1..=5).filter(|x| x % 2 == 1).exact_len(3).collect_into_hinted();
+    /// assert_eq!(suggested, odds.len(), "Exact length should suggest exactly");
+    /// ```
+    #[inline]
+    fn collect_into_hinted<C: Default + Extend<Self::Item>>(self) -> (C, usize) {
+        let hint: SizeHint = self.size_hint().try_into().expect("iterator's size hint should be valid");
+        let mut collection = C::default();
+        collection.extend(self);
+        (collection, hint.capacity(CapacityPolicy::Lower))
+    }
+
+    /// Wraps this [`Iterator`] in a [`PreferInner`] that tracks this [`Iterator::size_hint`],
+    /// but reports this iterator's own size hint whenever it is exact.
+    ///
+    /// [`HintSize::prefer_inner_when_exact`] can be used to track a custom size hint instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..3).filter(|_| true).chain(5..7).prefer_inner_when_exact();
+    /// assert_eq!(iter.size_hint(), (2, Some(4)), "Should match the initial size hint");
+    ///
+    /// iter.by_ref().take(3).for_each(drop);
+    /// assert_eq!(iter.size_hint(), (1, Some(1)), "Should match the now exact inner size hint");
+    /// ```
+    #[inline]
+    fn prefer_inner_when_exact(self) -> PreferInner<Self> {
+        PreferInner::new(self)
+    }
+
+    /// Wraps this [`Iterator`] in a [`Validated`] that panics if this [`Iterator::size_hint`] is
+    /// ever invalid when read.
+    ///
+    /// This is useful for catching buggy [`Iterator::size_hint`] implementations during testing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use size_hinter::SizeHinter;
+    ///
+    /// let mut iter = (1..5).filter(|x| x % 2 == 0).validated();
+    /// assert_eq!(iter.size_hint(), (0, Some(4)), "Valid size hint is passed through");
+    /// ```
+    #[inline]
+    fn validated(self) -> Validated<Self> {
+        Validated::new(self)
+    }
+
+    /// Returns this [`Iterator::size_hint`] as a validated [`SizeHint`].
+    ///
+    /// This is a snapshot of the current size hint, and will not change as this iterator is
+    /// advanced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSizeHint`] if this [`Iterator::size_hint`] is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, SizeHint, InvalidSizeHint, INVALID_UNIT_ITERATOR};
+    /// # fn main() -> Result<(), InvalidSizeHint> {
+    /// let hint = (1..5).filter(|x| x % 2 == 0).to_size_hint()?;
+    /// assert_eq!(hint, SizeHint::at_most(4), "Should match the filtered range's size hint");
+    ///
+    /// let err: InvalidSizeHint = INVALID_UNIT_ITERATOR.to_size_hint().expect_err("Size hint should be invalid");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn to_size_hint(&self) -> Result<SizeHint, crate::InvalidSizeHint> {
+        SizeHint::of(self)
+    }
+
+    /// Returns this [`Iterator::size_hint`] as a [`SizeHint`], panicking if it is invalid.
+    ///
+    /// This is the infallible counterpart to [`Self::to_size_hint`], for when this iterator's
+    /// size hint is known to be valid. Like [`Self::to_size_hint`], this is a snapshot of the
+    /// current size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Iterator::size_hint`] is invalid, that is, if its lower bound is greater
+    /// than its upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHinter, SizeHint};
+    /// let iter = (1..5).map(|x| x * 2);
+    /// assert_eq!(iter.peek_size_hint(), SizeHint::exact(4), "Should match the mapped range's size hint");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn peek_size_hint(&self) -> SizeHint {
+        self.to_size_hint().expect("iterator's size hint should be valid")
+    }
 }
 
 impl<I: Iterator> SizeHinter for I {}
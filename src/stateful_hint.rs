@@ -0,0 +1,117 @@
+use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
+
+use crate::SizeHint;
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that provides a custom [`Iterator::size_hint`], recomputed by a
+/// closure after each element is yielded.
+///
+/// Unlike [`HintSize`], which decrements its size hint by one per element, this adaptor applies
+/// the `update` closure to the current [`SizeHint`] to produce the next one. This is useful for
+/// modelling adaptors where each consumed element changes the remaining count non-uniformly, such
+/// as run-length decoders. Once the wrapped iterator returns [`None`], the size hint becomes
+/// [`SizeHint::ZERO`].
+///
+/// It is the caller's responsibility to ensure that the initial hint and `update` produce accurate
+/// bounds. Incorrect values may cause errors or panics in code that relies on this
+/// [`Iterator::size_hint`].
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::{SizeHint, SizeHinter};
+/// let mut iter = (1..=6).step_by(2).hint_each(SizeHint::exact(6), |hint| hint.saturating_sub(2));
+/// assert_eq!(iter.size_hint(), (6, Some(6)), "Should start at the initial hint");
+///
+/// assert_eq!(iter.next(), Some(1), "Should not change underlying iterator");
+/// assert_eq!(iter.size_hint(), (4, Some(4)), "Should apply update after each element");
+/// ```
+#[derive(Clone)]
+#[readonly::make]
+pub struct StatefulHint<I: Iterator, F: FnMut(SizeHint) -> SizeHint> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The current size hint.
+    pub hint: SizeHint,
+    /// The closure that computes the next size hint.
+    update: F,
+}
+
+impl<I: Iterator, F: FnMut(SizeHint) -> SizeHint> StatefulHint<I, F> {
+    /// Wraps `iterator` in a new [`StatefulHint`] whose size hint starts at `initial`, and is
+    /// recomputed by `update` after each element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, StatefulHint};
+    /// let iter = StatefulHint::new(1..5, SizeHint::at_most(8), |hint| hint.saturating_sub(2));
+    /// assert_eq!(iter.size_hint(), (0, Some(8)), "Should start at the initial hint");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>, initial: SizeHint, update: F) -> Self {
+        Self { iterator: iterator.into_iter(), hint: initial, update }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::{SizeHint, StatefulHint};
+    /// let iter = StatefulHint::new(1..5, SizeHint::exact(4), SizeHint::decrement);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+
+    /// Updates the size hint after advancing the wrapped iterator from either end, yielding `item`.
+    #[inline]
+    fn advance(&mut self, item: Option<I::Item>) -> Option<I::Item> {
+        self.hint = match item {
+            Some(_) => (self.update)(self.hint),
+            None => SizeHint::ZERO,
+        };
+        item
+    }
+}
+
+impl<I: Iterator, F: FnMut(SizeHint) -> SizeHint> Iterator for StatefulHint<I, F> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next();
+        self.advance(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.hint.into()
+    }
+}
+
+impl<I: DoubleEndedIterator, F: FnMut(SizeHint) -> SizeHint> DoubleEndedIterator for StatefulHint<I, F> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next_back();
+        self.advance(item)
+    }
+}
+
+impl<I: FusedIterator, F: FnMut(SizeHint) -> SizeHint> FusedIterator for StatefulHint<I, F> {}
+
+impl<I: Iterator + Debug, F: FnMut(SizeHint) -> SizeHint> Debug for StatefulHint<I, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatefulHint")
+            .field("iterator", &self.iterator)
+            .field("hint", &self.hint)
+            .finish_non_exhaustive()
+    }
+}
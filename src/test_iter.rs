@@ -4,7 +4,8 @@ use crate::SizeHint;
 
 /// A test [`Iterator`] that can not be iterated over, but has an arbitrary size hint.
 ///
-/// This is useful for testing how consumers handle various size hints.
+/// This is useful for testing how consumers handle various size hints. An iterator that yields no
+/// elements, rather than panicking, can be created with [`TestIterator::empty_with_hint`].
 ///
 /// # Type parameters
 ///
@@ -19,6 +20,7 @@ use crate::SizeHint;
 /// ```
 pub struct TestIterator<T = ()> {
     size_hint: (usize, Option<usize>),
+    empty: bool,
     _marker: core::marker::PhantomData<T>,
 }
 
@@ -40,7 +42,27 @@ impl<T> TestIterator<T> {
     /// ```
     #[must_use]
     pub const fn new(size_hint: (usize, Option<usize>)) -> Self {
-        Self { size_hint, _marker: core::marker::PhantomData }
+        Self { size_hint, empty: false, _marker: core::marker::PhantomData }
+    }
+
+    /// Creates a new [`TestIterator`] with the given `size_hint` that yields no elements.
+    ///
+    /// Unlike [`TestIterator::new`], [`Iterator::next`] and [`DoubleEndedIterator::next_back`]
+    /// return [`None`] rather than panicking. This is useful for testing how consumers react to an
+    /// iterator that claims elements but yields none. The validity of the size hint is not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::TestIterator;
+    /// let mut iter = TestIterator::<()>::empty_with_hint((5, Some(10)));
+    /// assert_eq!(iter.size_hint(), (5, Some(10)));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.size_hint(), (5, Some(10)));
+    /// ```
+    #[must_use]
+    pub const fn empty_with_hint(size_hint: (usize, Option<usize>)) -> Self {
+        Self { size_hint, empty: true, _marker: core::marker::PhantomData }
     }
 
     /// Creates a new [`TestIterator`] with an exact size hint.
@@ -90,7 +112,10 @@ impl<T> Iterator for TestIterator<T> {
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        unimplemented!("TestIterator is not iteratable");
+        match self.empty {
+            true => None,
+            false => unimplemented!("TestIterator is not iteratable"),
+        }
     }
 }
 
@@ -112,6 +137,9 @@ impl<T> ExactSizeIterator for TestIterator<T> {
 
 impl<T> DoubleEndedIterator for TestIterator<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        unimplemented!("TestIterator is not iteratable");
+        match self.empty {
+            true => None,
+            false => unimplemented!("TestIterator is not iteratable"),
+        }
     }
 }
@@ -0,0 +1,35 @@
+//! Utilities for testing consumers of size hints.
+
+use crate::SizeHint;
+
+/// A curated set of interesting [`SizeHint`]s, including edge cases.
+const SAMPLE_HINTS: [SizeHint; 11] = [
+    SizeHint::ZERO,
+    SizeHint::UNIVERSAL,
+    SizeHint::exact(1),
+    SizeHint::exact(10),
+    SizeHint::exact(usize::MAX),
+    SizeHint::at_most(1),
+    SizeHint::bounded(3, 10),
+    SizeHint::bounded(0, usize::MAX),
+    SizeHint::unbounded(1),
+    SizeHint::unbounded(5),
+    SizeHint::unbounded(usize::MAX),
+];
+
+/// Returns an [`Iterator`] over a curated set of interesting [`SizeHint`]s.
+///
+/// This includes [`SizeHint::ZERO`], [`SizeHint::UNIVERSAL`], exact, bounded, and unbounded hints,
+/// and hints at the extremes of `usize`. This is useful for table driven tests of code that
+/// consumes size hints.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::{testing::sample_hints, SizeHint};
+/// assert!(sample_hints().any(|hint| hint == SizeHint::UNIVERSAL), "should include the universal hint");
+/// assert!(sample_hints().all(|hint| SizeHint::try_from(hint.as_hint()).is_ok()), "should all be valid");
+/// ```
+pub fn sample_hints() -> impl Iterator<Item = SizeHint> {
+    SAMPLE_HINTS.into_iter()
+}
@@ -0,0 +1,101 @@
+use core::iter::FusedIterator;
+
+use crate::SizeHint;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that validates the wrapped iterator's [`Iterator::size_hint`] every
+/// time it is read.
+///
+/// Valid size hints are reported as is, but [`Iterator::size_hint`] panics if the wrapped iterator
+/// ever reports an invalid size hint (one where the lower bound is greater than the upper bound).
+/// This is useful for catching buggy [`Iterator::size_hint`] implementations during testing.
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut iter = (1..5).validated();
+/// assert_eq!(iter.size_hint(), (4, Some(4)), "Valid size hint is passed through");
+/// assert_eq!(iter.next(), Some(1), "Underlying iterator is not changed");
+/// ```
+///
+/// ```rust,should_panic
+/// # use size_hinter::{SizeHinter, INVALID_UNIT_ITERATOR};
+/// let iter = INVALID_UNIT_ITERATOR.validated();
+/// let _ = iter.size_hint(); // panics
+/// ```
+#[derive(Debug, Default, Clone)]
+#[readonly::make]
+pub struct Validated<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+}
+
+impl<I: Iterator> Validated<I> {
+    /// Wraps `iterator` in a new [`Validated`].
+    ///
+    /// The size hint of `iterator` is not validated until read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::Validated;
+    /// let iter = Validated::new(1..5);
+    /// assert_eq!(iter.size_hint(), (4, Some(4)), "Valid size hint is passed through");
+    /// ```
+    #[inline]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>) -> Self {
+        Self { iterator: iterator.into_iter() }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::Validated;
+    /// let iter = Validated::new(1..5);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator> Iterator for Validated<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator.next()
+    }
+
+    /// Returns the wrapped iterator's size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped iterator's size hint is invalid.
+    #[inline]
+    #[track_caller]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.iterator.size_hint();
+        assert!(SizeHint::try_from(hint).is_ok(), "iterator's size hint should be valid, but was {hint:?}");
+        hint
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Validated<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iterator.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Validated<I> {}
+
+impl<I: FusedIterator> FusedIterator for Validated<I> {}
@@ -0,0 +1,103 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::SizeHint;
+
+#[cfg(doc)]
+use crate::*;
+
+/// An [`Iterator`] adaptor that yields overlapping windows of `window` consecutive elements of the
+/// wrapped iterator, reporting a size hint consistent with the number of windows remaining.
+///
+/// Each window is yielded as a [`Vec`] of cloned elements. A sliding window of size `w` over `n`
+/// elements yields `n - w + 1` windows, or none if `n < w`, matching [`SizeHint::windowed`].
+///
+/// Note this type is readonly. The field values may be read, but not modified.
+///
+/// # Examples
+///
+/// ```rust
+/// # use size_hinter::SizeHinter;
+/// let mut iter = [1, 2, 3, 4].into_iter().windows_hint(2);
+/// assert_eq!(iter.size_hint(), (3, Some(3)), "Should report the number of windows");
+///
+/// assert_eq!(iter.next(), Some(vec![1, 2]));
+/// assert_eq!(iter.size_hint(), (2, Some(2)), "Should reflect the remaining windows");
+/// ```
+#[derive(Debug, Clone)]
+#[readonly::make]
+pub struct WindowsHint<I: Iterator> {
+    /// The underlying iterator.
+    pub iterator: I,
+    /// The size of each window.
+    pub window: usize,
+    /// The elements of the next window read so far.
+    buffer: VecDeque<I::Item>,
+}
+
+impl<I: Iterator> WindowsHint<I> {
+    /// Wraps `iterator` in a new [`WindowsHint`] that yields windows of size `window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::WindowsHint;
+    /// let iter = WindowsHint::new(1..5, 3);
+    /// assert_eq!(iter.size_hint(), (2, Some(2)), "Should report the number of windows");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn new(iterator: impl IntoIterator<IntoIter = I>, window: usize) -> Self {
+        assert!(window != 0, "window size should be non-zero");
+        Self { iterator: iterator.into_iter(), window, buffer: VecDeque::with_capacity(window) }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// Any elements buffered for the next window are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use size_hinter::WindowsHint;
+    /// let iter = WindowsHint::new(1..5, 2);
+    /// let inner: core::ops::Range<i32> = iter.into_inner();
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<I: Iterator<Item: Clone>> Iterator for WindowsHint<I> {
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.window {
+            self.buffer.push_back(self.iterator.next()?);
+        }
+        let window = self.buffer.iter().cloned().collect();
+        self.buffer.pop_front();
+        Some(window)
+    }
+
+    /// Returns the number of windows remaining, based on the wrapped iterator's size hint and the
+    /// number of elements already buffered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped iterator's size hint is invalid.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let wrapped = SizeHint::of(&self.iterator).expect("iterator's size hint should be valid");
+        wrapped.windowed(self.window - self.buffer.len()).into()
+    }
+}
+
+impl<I: FusedIterator<Item: Clone>> FusedIterator for WindowsHint<I> {}
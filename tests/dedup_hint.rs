@@ -0,0 +1,48 @@
+use size_hinter::{DedupHint, SizeHinter};
+
+const TEST_ARRAY: [usize; 5] = [1, 1, 2, 2, 3];
+
+#[test]
+fn initial_state() {
+    let iter = TEST_ARRAY.into_iter().dedup_hint();
+    let (lower, upper) = iter.size_hint();
+    assert!(lower <= 1, "lower bound should be at most 1");
+    assert_eq!(upper, Some(5), "upper bound should include every element");
+}
+
+#[test]
+fn dedups_adjacent() {
+    let iter = TEST_ARRAY.into_iter().dedup_hint();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3], "consecutive duplicates should be removed");
+}
+
+#[test]
+fn keeps_non_adjacent() {
+    let iter = DedupHint::new([1, 2, 1, 1, 2]);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 1, 2], "non-consecutive duplicates should be kept");
+}
+
+#[test]
+fn tracks_hint() {
+    let mut iter = TEST_ARRAY.into_iter().dedup_hint();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (1, Some(3)), "should reflect the remaining elements");
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.size_hint(), (1, Some(1)), "should reflect the remaining elements");
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.size_hint(), (0, Some(0)), "should be empty once exhausted");
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn empty() {
+    let iter = DedupHint::new(Vec::<usize>::new());
+    assert_eq!(iter.size_hint(), (0, Some(0)), "should be empty");
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn unbounded() {
+    let iter = (1..).dedup_hint();
+    assert_eq!(iter.size_hint(), (1, None), "should preserve an unbounded upper bound");
+}
@@ -0,0 +1,39 @@
+mod macros;
+
+use macros::*;
+
+use std::ops::Range;
+
+use size_hinter::*;
+
+const TEST_ITER: Range<usize> = 1..5;
+
+test_ctor!(initial_hint, DelayedHint::new(TEST_ITER, SizeHint::bounded(2, 6)) => hint: SizeHint::UNIVERSAL);
+test_ctor!(invalid, DelayedHint::new(INVALID_UNIT_ITERATOR, SizeHint::exact(1)) => panic: "iterator's size hint should be valid");
+test_ctor!(disjoint, DelayedHint::new(TEST_ITER, SizeHint::exact(10)) => panic: "Invalid size hint");
+test_ctor!(try_disjoint, DelayedHint::try_new(TEST_ITER, SizeHint::exact(10)) => Err);
+
+test_iter!(
+    forward,
+    TEST_ITER.delayed_hint(SizeHint::bounded(2, 6)) => hint: SizeHint::UNIVERSAL,
+    next => Some(1), hint: (1, Some(5));
+    next => Some(2), hint: (0, Some(4));
+    next => Some(3), hint: (0, Some(3));
+    next => Some(4), hint: (0, Some(2));
+);
+
+test_iter!(
+    backward,
+    TEST_ITER.delayed_hint(SizeHint::exact(4)) => hint: SizeHint::UNIVERSAL,
+    next_back => Some(4), hint: (3, Some(3));
+    next_back => Some(3), hint: (2, Some(2));
+);
+
+#[test]
+fn started() {
+    let mut iter = TEST_ITER.delayed_hint(SizeHint::exact(4));
+    assert!(!iter.started, "should not be started before the first element");
+    iter.next();
+    assert!(iter.started, "should be started after the first element");
+    assert!(matches!(iter.into_inner(), Range { .. }));
+}
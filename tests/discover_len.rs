@@ -0,0 +1,49 @@
+use std::ops::Range;
+
+use size_hinter::{DiscoverLen, SizeHinter};
+
+const TEST_ITER: Range<usize> = 1..5;
+
+#[test]
+fn initial_state() {
+    let iter = TEST_ITER.discover_len();
+    assert_eq!(iter.size_hint(), (4, Some(4)), "size_hint should be unchanged");
+    assert_eq!(iter.discovered(), None, "len should not be discovered before exhaustion");
+    assert!(matches!(iter.into_inner(), Range { .. }));
+}
+
+#[test]
+fn partially_consumed() {
+    let mut iter = TEST_ITER.discover_len();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.count, 2, "count should track yielded elements");
+    assert_eq!(iter.discovered(), None, "len should not be discovered before exhaustion");
+}
+
+#[test]
+fn fully_consumed() {
+    let mut iter = TEST_ITER.discover_len();
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![1, 2, 3, 4], "elements should be unchanged");
+    assert_eq!(iter.discovered(), Some(4), "len should be discovered after exhaustion");
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.discovered(), Some(4), "len should not change after exhaustion");
+}
+
+#[test]
+fn fully_consumed_backward() {
+    let mut iter = DiscoverLen::new(TEST_ITER.filter(|x| x % 2 == 0));
+    assert_eq!(iter.by_ref().rev().collect::<Vec<_>>(), vec![4, 2], "elements should be unchanged");
+    assert_eq!(iter.discovered(), Some(2), "len should be discovered after exhaustion");
+}
+
+#[test]
+fn reuse_discovered_len() {
+    let make_iter = || TEST_ITER.filter(|x| x % 2 == 1);
+    let mut first = make_iter().discover_len();
+    first.by_ref().for_each(drop);
+    let len = first.discovered().expect("len should be discovered");
+
+    let second = make_iter().exact_len(len);
+    assert_eq!(second.len(), 2, "discovered len should be usable for a second pass");
+}
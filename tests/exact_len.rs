@@ -61,3 +61,269 @@ test_iter!(
     next_back => None::<usize>, len: 0;
     next_back => None::<usize>, len: 0;
 );
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed() {
+    let mut boxed: Box<dyn Iterator<Item = usize>> = TEST_ITER.exact_len(TEST_LEN).boxed();
+    assert_eq!(boxed.size_hint(), (TEST_LEN, Some(TEST_LEN)), "boxed size_hint should match len");
+    assert_eq!(boxed.next(), Some(1), "boxed iterator should be unchanged");
+    assert_eq!(boxed.size_hint(), (3, Some(3)), "boxed size_hint should reflect the new state");
+}
+
+#[test]
+fn collect_into_hinted() {
+    let (collected, suggested): (Vec<usize>, usize) = TEST_ITER.exact_len(TEST_LEN).collect_into_hinted();
+    assert_eq!(collected, vec![1, 2, 3, 4], "should collect all elements");
+    assert_eq!(suggested, collected.len(), "suggested should match the final length");
+    assert!(collected.capacity() >= suggested, "capacity should be at least the suggested amount");
+}
+
+#[test]
+fn eq_hint_tuple() {
+    let mut iter = TEST_ITER.exact_len(TEST_LEN);
+    assert_eq!(iter, (TEST_LEN, Some(TEST_LEN)), "should equal the current size hint");
+    assert_ne!(iter, (0, Some(TEST_LEN)), "should not equal a different size hint");
+
+    iter.next();
+    assert_eq!(iter, (3, Some(3)), "should equal the new size hint");
+}
+
+test_iter!(
+    alternating_iteration,
+    ExactLen::new(1..5, 4) => len: 4,
+    next => Some(1), len: 3;
+    next_back => Some(4), len: 2;
+    next => Some(2), len: 1;
+    next_back => Some(3), len: 0;
+    next => None::<usize>, len: 0;
+    next_back => None::<usize>, len: 0;
+);
+
+#[cfg(debug_assertions)]
+mod inaccurate_len {
+    use super::*;
+
+    macros::panics!(
+        too_small,
+        (1..5).filter(|_| true).exact_len(3).for_each(drop),
+        "len should match the number of elements yielded"
+    );
+    macros::panics!(
+        too_large,
+        (1..5).filter(|x| x % 2 == 0).exact_len(4).rev().for_each(drop),
+        "len should match the number of elements yielded"
+    );
+    macros::panics!(
+        nth_too_small,
+        (1..10).filter(|_| true).exact_len(3).nth(5),
+        "len should match the number of elements yielded"
+    );
+}
+
+mod assert_exact_len {
+    use super::*;
+
+    test_iter!(
+        accurate,
+        (1..5).filter(|x| x % 2 == 0).assert_exact_len(2) => len: 2,
+        next => Some(2), len: 1;
+        next => Some(4), len: 0;
+        next => None::<usize>, len: 0;
+    );
+
+    test_ctor!(out_of_bounds, TEST_ITER.assert_exact_len(5) => panic: "len should be within the wrapped iterator's size hint bounds");
+
+    #[cfg(debug_assertions)]
+    macros::panics!(
+        too_large,
+        (1..5).filter(|x| x % 2 == 0).assert_exact_len(3).for_each(drop),
+        "len should match the number of elements yielded"
+    );
+}
+
+mod relax {
+    use super::*;
+
+    test_ctor!(slack, ExactLen::new(TEST_ITER, TEST_LEN).relax(1) => hint: (3, Some(5)));
+    test_ctor!(no_slack, ExactLen::new(TEST_ITER, TEST_LEN).relax(0) => hint: (4, Some(4)));
+    test_ctor!(saturating, ExactLen::new(TEST_ITER, TEST_LEN).relax(usize::MAX) => hint: (0, Some(usize::MAX)));
+
+    #[test]
+    #[should_panic(expected = "Invalid size hint")]
+    fn disjoint() {
+        let mut iter = TEST_ITER.filter(|x| x % 2 == 0).exact_len(TEST_LEN);
+        iter.next();
+        let _ = iter.relax(0);
+    }
+}
+
+mod skip_exact {
+    use super::*;
+
+    test_iter!(
+        skip_two,
+        ExactLen::new(1..6, 5).skip_exact(2) => len: 3,
+        next => Some(3), len: 2;
+        next_back => Some(5), len: 1;
+        next => Some(4), len: 0;
+        next => None::<usize>, len: 0;
+    );
+
+    test_iter!(
+        skip_none,
+        TEST_ITER.exact_len(TEST_LEN).skip_exact(0) => len: TEST_LEN,
+        next => Some(1), len: 3;
+    );
+
+    test_iter!(
+        skip_past_end,
+        TEST_ITER.exact_len(TEST_LEN).skip_exact(10) => len: 0,
+        next => None::<usize>, len: 0;
+    );
+}
+
+mod nth {
+    use super::*;
+
+    #[test]
+    fn forward() {
+        let mut iter = (1..10).exact_len(9);
+        assert_eq!(iter.nth(2), Some(3));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.nth(5), Some(9));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn backward() {
+        let mut iter = (1..10).exact_len(9);
+        assert_eq!(iter.nth_back(1), Some(8));
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.size_hint(), (7, Some(7)));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.nth_back(usize::MAX), None);
+        assert_eq!(iter.len(), 0);
+    }
+}
+
+mod split_at {
+    use super::*;
+
+    const SLICE: [usize; 5] = [1, 2, 3, 4, 5];
+
+    #[test]
+    fn middle() {
+        let (left, right) = SLICE.iter().exact_len(5).split_at(2);
+        assert_eq!(left.len(), 2);
+        assert_eq!(right.len(), 3);
+        assert_eq!(left.copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(right.copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn ends() {
+        let (left, right) = SLICE.iter().exact_len(5).split_at(0);
+        assert_eq!((left.len(), right.len()), (0, 5));
+        let (left, right) = SLICE.iter().exact_len(5).split_at(5);
+        assert_eq!((left.len(), right.len()), (5, 0));
+    }
+
+    #[test]
+    fn after_advance() {
+        let mut iter = SLICE.iter().exact_len(5);
+        iter.next();
+        let (left, right) = iter.split_at(2);
+        assert_eq!(left.copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(right.copied().collect::<Vec<_>>(), vec![4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mid should not be greater than len")]
+    fn past_end() {
+        let _ = SLICE.iter().exact_len(5).split_at(6);
+    }
+}
+
+#[test]
+fn into_len() {
+    let mut iter = TEST_ITER.exact_len(TEST_LEN);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.into_len(), 2, "should be the remaining length");
+}
+
+#[test]
+fn debug() {
+    let mut iter = TEST_ITER.exact_len(TEST_LEN);
+    assert_eq!(format!("{iter:?}"), "ExactLen { len: 4, iterator: 1..5 }");
+    iter.next();
+    assert!(format!("{iter:?}").starts_with("ExactLen { len: 3, "), "should start with the len");
+}
+
+#[test]
+fn clone() {
+    let mut iter = TEST_ITER.exact_len(TEST_LEN);
+    iter.next();
+
+    let mut clone = iter.clone();
+    assert_eq!(clone.len(), 3, "clone should copy the remaining len");
+    assert_eq!(clone.next(), Some(2), "clone should copy the inner iterator state");
+    assert_eq!(clone.len(), 2, "clone should track its own len");
+    assert_eq!(iter.len(), 3, "consuming the clone should not affect the original");
+    assert_eq!(iter.next(), Some(2), "consuming the clone should not affect the original");
+}
+
+mod hash {
+    use super::*;
+
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn equal() {
+        let set: HashSet<_> = [TEST_ITER.exact_len(TEST_LEN), TEST_ITER.exact_len(TEST_LEN)].into_iter().collect();
+        assert_eq!(set.len(), 1, "equal adaptors should hash equally");
+    }
+
+    #[test]
+    fn different_len() {
+        let mut a = TEST_ITER.exact_len(TEST_LEN);
+        let b = a.clone();
+        a.next();
+        let state = RandomState::new();
+        assert_ne!(a, b, "consumed adaptor should differ");
+        assert_ne!(state.hash_one(&a), state.hash_one(&b), "consumed adaptor should hash differently");
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod into_par {
+    use super::*;
+
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    #[test]
+    fn sum() {
+        let make_iter = || (1..=100).filter(|x| x % 3 == 0).exact_len(33);
+        let sequential: usize = make_iter().sum();
+        let parallel: usize = make_iter().into_par().sum();
+        assert_eq!(parallel, sequential, "parallel sum should match sequential sum");
+    }
+
+    #[test]
+    fn len() {
+        let mut iter = TEST_ITER.exact_len(TEST_LEN);
+        iter.next();
+        assert_eq!(iter.into_par().len(), 3, "should have the remaining length");
+    }
+
+    #[test]
+    fn order() {
+        let iter = TEST_ITER.exact_len(TEST_LEN).into_par();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4], "should preserve order");
+    }
+}
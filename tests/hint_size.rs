@@ -26,6 +26,44 @@ mod try_hint {
     test_ctor!(lower_too_large, TEST_ITER.try_hint_size(6, 10) => Err);
 }
 
+mod exact {
+    use super::*;
+
+    test_ctor!(valid, TEST_ITER.hint_exact(4) => hint: (4, Some(4)));
+    test_ctor!(filtered, TEST_ITER.filter(|x| x % 2 == 0).hint_exact(2) => size_hint: (2, Some(2)));
+    test_ctor!(too_large, TEST_ITER.hint_exact(5) => panic: "Invalid size hint");
+    test_ctor!(too_small, TEST_ITER.hint_exact(3) => panic: "Invalid size hint");
+    test_ctor!(try_valid, TEST_ITER.try_hint_exact(4).unwrap() => hint: (4, Some(4)));
+    test_ctor!(try_too_large, TEST_ITER.try_hint_exact(5) => Err);
+    test_ctor!(invalid, INVALID_UNIT_ITERATOR.hint_exact(1) => panic: "iterator's size hint should be valid");
+
+    test_iter!(
+        decrements,
+        TEST_ITER.filter(|x| x % 2 == 0).hint_exact(2) => hint: (2, Some(2)),
+        next => Some(2), hint: (1, Some(1));
+        next => Some(4), hint: (0, Some(0));
+        next => None::<usize>, hint: (0, Some(0));
+    );
+}
+
+mod range {
+    use super::*;
+
+    test_ctor!(inclusive, TEST_ITER.hint_range(2..=6) => hint: (2, Some(6)));
+    test_ctor!(exclusive, TEST_ITER.hint_range(2..7) => hint: (2, Some(6)));
+    test_ctor!(from, TEST_ITER.hint_range(2..) => hint: SizeHint::unbounded(2));
+    test_ctor!(full, TEST_ITER.hint_range(..) => hint: SizeHint::UNIVERSAL);
+    test_ctor!(to, TEST_ITER.hint_range(..7) => hint: (0, Some(6)));
+    test_ctor!(to_inclusive, TEST_ITER.hint_range(..=6) => hint: (0, Some(6)));
+    test_ctor!(empty, TEST_ITER.hint_range(3..3) => panic: "Invalid size hint");
+    test_ctor!(empty_to, TEST_ITER.hint_range(..0) => panic: "Invalid size hint");
+    test_ctor!(disjoint, TEST_ITER.hint_range(5..) => panic: "Invalid size hint");
+    test_ctor!(try_valid, TEST_ITER.try_hint_range(2..=6).unwrap() => hint: (2, Some(6)));
+    test_ctor!(try_empty, TEST_ITER.try_hint_range(3..3) => Err);
+    test_ctor!(try_empty_to, TEST_ITER.try_hint_range(..0) => Err);
+    test_ctor!(try_disjoint, TEST_ITER.try_hint_range(..=3) => Err);
+}
+
 mod min {
     use super::*;
 
@@ -33,6 +71,131 @@ mod min {
     test_ctor!(lower_too_large, TEST_ITER.hint_min(6) => panic: "Invalid size hint");
 }
 
+mod max {
+    use super::*;
+
+    test_ctor!(valid, TEST_ITER.hint_max(6) => hint: (0, Some(6)));
+    test_ctor!(exact, TEST_ITER.hint_max(4) => hint: (0, Some(4)));
+    test_ctor!(upper_too_small, TEST_ITER.hint_max(3) => panic: "Invalid size hint");
+
+    test_iter!(
+        decrements,
+        TEST_ITER.hint_max(6) => hint: (0, Some(6)),
+        next => Some(1), hint: (0, Some(5));
+        next_back => Some(4), hint: (0, Some(4));
+    );
+}
+
+mod upper {
+    use super::*;
+
+    test_ctor!(valid, (1..10).hint_upper(12) => hint: (9, Some(12)));
+    test_ctor!(exact, (1..10).hint_upper(9) => hint: (9, Some(9)));
+    test_ctor!(tightened, (1..10).filter(|x| x % 2 == 0).hint_upper(4) => size_hint: (0, Some(4)));
+    test_ctor!(upper_too_small, (1..10).hint_upper(5) => panic: "Invalid size hint");
+}
+
+mod optional {
+    use super::*;
+
+    test_ctor!(valid, (1..2).hint_optional() => hint: (0, Some(1)));
+    test_ctor!(empty, (1..1).hint_optional() => hint: (0, Some(1)));
+    test_ctor!(lower_too_large, TEST_ITER.hint_optional() => panic: "Invalid size hint");
+}
+
+mod or_identity {
+    use super::*;
+
+    fn evens() -> std::iter::Filter<Range<usize>, fn(&usize) -> bool> {
+        (1..10).filter(|x| x % 2 == 0)
+    }
+
+    test_ctor!(valid, TEST_ITER.hint_or_identity(3, 5) => hint: (3, Some(5)));
+    test_ctor!(not_clamped, evens().hint_or_identity(2, 20) => size_hint: (2, Some(20)));
+    test_ctor!(invalid_bounds, TEST_ITER.hint_or_identity(5, 3) => hint: (4, Some(4)));
+    test_ctor!(disjoint, TEST_ITER.hint_or_identity(6, 10) => hint: (4, Some(4)));
+    test_ctor!(invalid_wrapped, INVALID_UNIT_ITERATOR.hint_or_identity(1, 2) => panic: "iterator's size hint should be valid");
+}
+
+mod estimate {
+    use super::*;
+
+    test_ctor!(valid, TEST_ITER.hint_estimate(0, 8) => hint: (0, Some(8)));
+    test_ctor!(invalid_bounds, TEST_ITER.hint_estimate(5, 3) => panic: "Invalid size hint");
+    test_ctor!(estimate_too_small, TEST_ITER.hint_estimate(0, 2) => panic: "Invalid size hint");
+    test_ctor!(lower_too_large, TEST_ITER.hint_estimate(6, 10) => panic: "Invalid size hint");
+}
+
+mod from {
+    use super::*;
+
+    test_ctor!(scaled, TEST_ITER.hint_from(|iter| SizeHint::bounded(iter.len(), iter.len() * 2)) => hint: (4, Some(8)));
+    test_ctor!(unbounded, TEST_ITER.hint_from(|iter| SizeHint::unbounded(iter.len() / 2)) => hint: (2, None));
+    test_ctor!(disjoint, TEST_ITER.hint_from(|iter| SizeHint::exact(iter.len()).repeat(2)) => panic: "Invalid size hint");
+}
+
+#[cfg(feature = "alloc")]
+mod refine {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        let iter = (1..=5).refine_hint(2);
+        assert_eq!(iter.size_hint(), (5, Some(5)), "should combine the buffered and remaining hints");
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5], "should yield all elements in order");
+    }
+
+    #[test]
+    fn narrowed() {
+        let mut iter = (1..=10).filter(|x| x % 2 == 0).refine_hint(2);
+        assert_eq!(iter.size_hint(), (2, Some(8)), "should count the buffered elements exactly");
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.size_hint(), (1, Some(7)), "should reflect new state");
+        assert_eq!(iter.collect::<Vec<_>>(), vec![4, 6, 8, 10], "should yield all elements in order");
+    }
+
+    #[test]
+    fn sample_past_end() {
+        let iter = TEST_ITER.refine_hint(10);
+        assert_eq!(iter.size_hint(), (4, Some(4)), "should buffer every element");
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4], "should yield all elements in order");
+    }
+
+    #[test]
+    fn unbounded() {
+        let iter = (1..).refine_hint(2);
+        assert_eq!(iter.size_hint(), (usize::MAX, None), "should preserve an unbounded upper bound");
+    }
+}
+
+mod like {
+    use super::*;
+
+    const NAMES: [&str; 2] = ["a", "b"];
+
+    test_ctor!(exact, TEST_ITER.filter(|x| x % 2 == 0).hint_like(&NAMES.iter()).unwrap() => size_hint: (2, Some(2)));
+    test_ctor!(same, TEST_ITER.hint_like(&(5..9)).unwrap() => hint: (4, Some(4)));
+    test_ctor!(universal, TEST_ITER.hint_like(&TEST_ITER.hide_size()).unwrap() => hint: (0, None));
+    test_ctor!(disjoint, TEST_ITER.hint_like(&NAMES.iter()) => Err);
+    test_ctor!(invalid_other, TEST_ITER.hint_like(&INVALID_UNIT_ITERATOR) => Err);
+}
+
+mod clamped {
+    use super::*;
+
+    fn evens() -> std::iter::Filter<Range<usize>, fn(&usize) -> bool> {
+        (1..10).filter(|x| x % 2 == 0)
+    }
+
+    test_ctor!(within, evens().hint_size_clamped(2, 5) => size_hint: (2, Some(5)));
+    test_ctor!(upper_clamped, evens().hint_size_clamped(2, 20) => size_hint: (2, Some(9)));
+    test_ctor!(lower_clamped, TEST_ITER.hide_size().hint_size_clamped(0, 3) => size_hint: (0, Some(3)));
+    test_ctor!(both_clamped, TEST_ITER.hint_size_clamped(0, 10) => size_hint: (4, Some(4)));
+    test_ctor!(disjoint, TEST_ITER.hint_size_clamped(6, 10) => size_hint: (4, Some(4)));
+    test_ctor!(invalid_bounds, evens().hint_size_clamped(5, 3) => size_hint: (0, Some(9)));
+    test_ctor!(invalid_wrapped, INVALID_UNIT_ITERATOR.hint_size_clamped(1, 2) => panic: "iterator's size hint should be valid");
+}
+
 mod try_min {
     use super::*;
 
@@ -40,9 +203,237 @@ mod try_min {
     test_ctor!(lower_too_large, TEST_ITER.try_hint_min(6) => Err);
 }
 
+mod try_max {
+    use super::*;
+
+    test_ctor!(valid, TEST_ITER.try_hint_max(6).unwrap() => hint: (0, Some(6)));
+    test_ctor!(upper_too_small, TEST_ITER.try_hint_max(3) => Err);
+}
+
+mod tighten {
+    use super::*;
+
+    fn evens() -> std::iter::Filter<Range<usize>, fn(&usize) -> bool> {
+        (1..10).filter(|x| x % 2 == 0)
+    }
+
+    #[test]
+    fn upper() {
+        let iter = evens().tighten(SizeHint::at_most(5)).expect("hints should overlap");
+        assert_eq!(iter.size_hint(), (0, Some(5)));
+    }
+
+    #[test]
+    fn lower() {
+        let iter = evens().tighten(SizeHint::unbounded(2)).expect("hints should overlap");
+        assert_eq!(iter.size_hint(), (2, Some(9)));
+    }
+
+    #[test]
+    fn never_loosens() {
+        let iter = TEST_ITER.tighten(SizeHint::UNIVERSAL).expect("hints should overlap");
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn disjoint() {
+        assert_eq!(TEST_ITER.tighten(SizeHint::at_most(3)).expect_err("hints should be disjoint"), InvalidSizeHint);
+    }
+
+    test_ctor!(invalid, INVALID_UNIT_ITERATOR.tighten(SizeHint::UNIVERSAL) => panic: "iterator's size hint should be valid");
+}
+
 test_ctor!(hidden, TEST_ITER.hide_size() => hint: SizeHint::UNIVERSAL);
 test_ctor!(default, HintSize::<Range<usize>>::default() => hint: SizeHint::UNIVERSAL);
 
+#[test]
+fn into_hint() {
+    let mut iter = TEST_ITER.hint_size(2, 6);
+    assert_eq!(iter.by_ref().take(2).count(), 2);
+    assert_eq!(iter.into_hint(), SizeHint::at_most(4), "should be the tracked size hint");
+}
+
+mod set_hint {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let mut iter = TEST_ITER.hint_size(0, 10);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.set_hint(SizeHint::exact(3)), Ok(()));
+        assert_eq!(iter.size_hint(), (3, Some(3)), "should reflect the new size hint");
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.size_hint(), (2, Some(2)), "should track the new size hint");
+    }
+
+    #[test]
+    fn invalid_preserves_hint() {
+        let mut iter = TEST_ITER.hint_size(0, 10);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(
+            iter.set_hint(SizeHint::exact(4)),
+            Err(InvalidSizeHint),
+            "hint should be above the remaining length"
+        );
+        assert_eq!(
+            iter.set_hint(SizeHint::at_most(2)),
+            Err(InvalidSizeHint),
+            "hint should be below the remaining length"
+        );
+        assert_eq!(iter.size_hint(), (0, Some(9)), "should keep the previous size hint");
+    }
+
+    test_ctor!(with_hint, TEST_ITER.hint_size(0, 10).with_hint(SizeHint::bounded(2, 6)).unwrap() => hint: (2, Some(6)));
+    test_ctor!(with_hint_invalid, TEST_ITER.hint_size(0, 10).with_hint(SizeHint::unbounded(5)) => Err);
+}
+
+mod map_hint {
+    use super::*;
+
+    test_ctor!(
+        scale_then_clamp,
+        TEST_ITER.hint_size(1, 4)
+            .map_hint(|hint| hint.scale(2))
+            .and_then(|iter| iter.map_hint(|hint| hint.clamp(3, 6).expect("clamp should be valid")))
+            .unwrap()
+            => hint: (3, Some(6))
+    );
+    test_ctor!(
+        scale_out_of_range,
+        TEST_ITER.hint_size(3, 5).map_hint(|hint| hint.scale(2))
+            => Err
+    );
+}
+
+mod nth {
+    use super::*;
+
+    #[test]
+    fn forward() {
+        let mut iter = (1..10).hint_size(5, 9);
+        assert_eq!(iter.nth(2), Some(3));
+        assert_eq!(iter.size_hint(), (2, Some(6)));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.size_hint(), (1, Some(5)));
+        assert_eq!(iter.nth(1), Some(6));
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_eq!(iter.nth(5), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn backward() {
+        let mut iter = (1..10).hint_size(5, 9);
+        assert_eq!(iter.nth_back(2), Some(7));
+        assert_eq!(iter.size_hint(), (2, Some(6)));
+        assert_eq!(iter.nth_back(usize::MAX), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
+
+mod take {
+    use super::*;
+
+    test_iter!(
+        from_min,
+        TEST_ITER.hint_min(2).take(3) => hint: (2, Some(3)),
+        next => Some(1), hint: (1, Some(2));
+        next => Some(2), hint: (0, Some(1));
+        next => Some(3), hint: (0, Some(0));
+        next => None::<usize>, hint: (0, Some(0));
+    );
+
+    test_ctor!(capped_lower, (1..10).hint_min(5).take(3) => size_hint: (3, Some(3)));
+    test_ctor!(capped_upper, TEST_ITER.hint_size(2, 6).take(5) => size_hint: (2, Some(5)));
+    test_ctor!(within, TEST_ITER.hint_size(2, 5).take(6) => size_hint: (2, Some(5)));
+    test_ctor!(hidden, TEST_ITER.hide_size().take(2) => size_hint: (0, Some(2)));
+    test_ctor!(zero, TEST_ITER.hint_size(2, 6).take(0) => size_hint: (0, Some(0)));
+}
+
+mod cycle_capped {
+    use super::*;
+
+    test_iter!(
+        cycles,
+        (1..3).hint_size(1, 3).cycle_capped(5) => hint: (5, Some(5)),
+        next => Some(1), hint: (4, Some(4));
+        next => Some(2), hint: (3, Some(3));
+        next => Some(1), hint: (2, Some(2));
+        next => Some(2), hint: (1, Some(1));
+        next => Some(1), hint: (0, Some(0));
+        next => None::<usize>, hint: (0, Some(0));
+    );
+
+    test_ctor!(zero, TEST_ITER.hide_size().cycle_capped(0) => size_hint: (0, Some(0)));
+    test_ctor!(empty_zero, (0..0).hide_size().cycle_capped(0) => size_hint: (0, Some(0)));
+    test_ctor!(empty, (0..0).hide_size().cycle_capped(5) => panic: "wrapped iterator should be known to be non-empty");
+    test_ctor!(
+        possibly_empty,
+        (1..3).filter(|_| false).hide_size().cycle_capped(5) => panic: "wrapped iterator should be known to be non-empty"
+    );
+    test_ctor!(possibly_empty_zero, (1..3).filter(|_| false).hide_size().cycle_capped(0) => size_hint: (0, Some(0)));
+}
+
+mod next_if {
+    use super::*;
+
+    #[test]
+    fn consumes_on_match() {
+        let mut iter = TEST_ITER.peekable().hint_size(3, 5);
+
+        assert_eq!(iter.next_if(|&x| x == 1), Some(1));
+        assert_eq!(iter.size_hint(), (2, Some(4)));
+    }
+
+    #[test]
+    fn untouched_on_mismatch() {
+        let mut iter = TEST_ITER.peekable().hint_size(3, 5);
+
+        assert_eq!(iter.next_if(|&x| x == 5), None);
+        assert_eq!(iter.size_hint(), (3, Some(5)));
+        assert_eq!(iter.next(), Some(1));
+    }
+
+    #[test]
+    fn untouched_on_exhausted() {
+        let mut iter = (0..0).peekable().hint_size(0, 1);
+
+        assert_eq!(iter.next_if(|_| true), None);
+        assert_eq!(iter.size_hint(), (0, Some(1)));
+    }
+}
+
+mod wrap_dyn {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let mut range = TEST_ITER;
+        let dyn_iter: &mut dyn Iterator<Item = usize> = &mut range;
+        {
+            let mut iter = HintSize::wrap_dyn(dyn_iter, 3, 5);
+            assert_eq!(iter.size_hint(), (3, Some(5)));
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.size_hint(), (2, Some(4)));
+        }
+        assert_eq!(range, 2..5, "underlying iterator should be advanced");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid size hint")]
+    fn lower_too_large() {
+        let mut range = TEST_ITER;
+        _ = HintSize::wrap_dyn(&mut range, 6, 10);
+    }
+
+    #[test]
+    fn try_invalid() {
+        let mut range = TEST_ITER;
+        assert!(HintSize::try_wrap_dyn(&mut range, 6, 10).is_err(), "lower bound is > iterator's upper bound");
+        assert!(HintSize::try_wrap_dyn(&mut range, 5, 3).is_err(), "lower bound is > upper bound");
+    }
+}
+
 mod panic_on_invalid {
     use super::*;
     use size_hinter::INVALID_UNIT_ITERATOR;
@@ -51,6 +442,8 @@ mod panic_on_invalid {
     test_ctor!(try_new, HintSize::try_new(INVALID_UNIT_ITERATOR, 1, 2) => panic: "iterator's size hint should be valid");
     test_ctor!(min, HintSize::min(INVALID_UNIT_ITERATOR, 1) => panic: "iterator's size hint should be valid");
     test_ctor!(try_min, HintSize::try_min(INVALID_UNIT_ITERATOR, 1) => panic: "iterator's size hint should be valid");
+    test_ctor!(max, HintSize::max(INVALID_UNIT_ITERATOR, 1) => panic: "iterator's size hint should be valid");
+    test_ctor!(try_max, HintSize::try_max(INVALID_UNIT_ITERATOR, 1) => panic: "iterator's size hint should be valid");
 }
 
 mod iter {
@@ -116,3 +509,79 @@ mod iter {
         next_back => None::<usize>, hint: (0, Some(0));
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed() {
+    let mut boxed: Box<dyn Iterator<Item = usize>> = TEST_ITER.hint_size(3, 5).boxed();
+    assert_eq!(boxed.size_hint(), (3, Some(5)), "boxed size_hint should match hint");
+    assert_eq!(boxed.next(), Some(1), "boxed iterator should be unchanged");
+    assert_eq!(boxed.size_hint(), (2, Some(4)), "boxed size_hint should reflect the new state");
+}
+
+#[test]
+fn collect_into_hinted() {
+    let (collected, suggested): (Vec<usize>, usize) = TEST_ITER.hint_size(2, 6).collect_into_hinted();
+    assert_eq!(collected, vec![1, 2, 3, 4], "should collect all elements");
+    assert_eq!(suggested, 2, "suggested should match the hint's lower bound");
+}
+
+#[test]
+fn eq_hint_tuple() {
+    let mut iter = TEST_ITER.hint_size(3, 5);
+    assert_eq!(iter, (3, Some(5)), "should equal the current size hint");
+    assert_ne!(iter, (3, None), "should not equal a different size hint");
+
+    iter.next();
+    assert_eq!(iter, (2, Some(4)), "should equal the new size hint");
+}
+
+mod debug {
+    use super::*;
+
+    #[test]
+    fn bounded() {
+        let iter = TEST_ITER.hint_size(3, 5);
+        assert_eq!(format!("{iter:?}"), "HintSize { hint: 3..=5, iterator: 1..5 }");
+    }
+
+    #[test]
+    fn hidden() {
+        let iter = TEST_ITER.hide_size();
+        assert!(format!("{iter:?}").starts_with("HintSize { hint: .., "), "should start with the hint");
+    }
+}
+
+#[test]
+fn clone() {
+    let mut iter = TEST_ITER.hint_size(3, 5);
+    iter.next();
+
+    let mut clone = iter.clone();
+    assert_eq!(clone.size_hint(), (2, Some(4)), "clone should copy the current hint");
+    assert_eq!(clone.next(), Some(2), "clone should copy the inner iterator state");
+    assert_eq!(clone.size_hint(), (1, Some(3)), "clone should track its own hint");
+    assert_eq!(iter.size_hint(), (2, Some(4)), "consuming the clone should not affect the original");
+    assert_eq!(iter.next(), Some(2), "consuming the clone should not affect the original");
+}
+
+mod hash {
+    use super::*;
+
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn equal() {
+        let set: HashSet<_> = [TEST_ITER.hint_size(3, 5), TEST_ITER.hint_size(3, 5)].into_iter().collect();
+        assert_eq!(set.len(), 1, "equal adaptors should hash equally");
+    }
+
+    #[test]
+    fn different_hint() {
+        let (a, b) = (TEST_ITER.hint_size(3, 5), TEST_ITER.hint_size(2, 5));
+        assert_ne!(a, b, "adaptors with different hints should differ");
+        let state = RandomState::new();
+        assert_ne!(state.hash_one(&a), state.hash_one(&b), "adaptors with different hints should hash differently");
+    }
+}
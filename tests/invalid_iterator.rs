@@ -12,3 +12,38 @@ fn reports_invalid_size_hint() {
     let (lower, upper) = iter.size_hint();
     assert!(lower > upper.unwrap(), "Size hint should be invalid");
 }
+
+mod const_invalid {
+    use super::*;
+    use size_hinter::ConstInvalidIterator;
+
+    macros::panics!(panics_on_next, ConstInvalidIterator::<()>::new().next(), "ConstInvalidIterator is not iteratable");
+    macros::panics!(
+        panics_on_next_back,
+        ConstInvalidIterator::<()>::new().next_back(),
+        "ConstInvalidIterator is not iteratable"
+    );
+    macros::panics!(
+        panics_on_len,
+        ConstInvalidIterator::<()>::new().len(),
+        "ConstInvalidIterator does not have a valid len"
+    );
+
+    #[test]
+    fn default_hint() {
+        let iter = ConstInvalidIterator::<()>::new();
+        assert_eq!(iter.size_hint(), InvalidIterator::<()>::INVALID_SIZE_HINT);
+    }
+
+    #[test]
+    fn distinct_hints() {
+        let a = ConstInvalidIterator::<(), 7, 3>::new();
+        let b = ConstInvalidIterator::<(), 20, 0>::new();
+        assert_eq!(a.size_hint(), (7, Some(3)));
+        assert_eq!(b.size_hint(), (20, Some(0)));
+        assert_ne!(
+            core::any::TypeId::of::<ConstInvalidIterator<(), 7, 3>>(),
+            core::any::TypeId::of::<ConstInvalidIterator<(), 20, 0>>()
+        );
+    }
+}
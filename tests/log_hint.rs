@@ -0,0 +1,65 @@
+#![cfg(feature = "log")]
+
+use std::sync::{Mutex, Once};
+
+use log::{LevelFilter, Log, Metadata, Record};
+use size_hinter::{LogHint, SizeHinter};
+
+/// A logger that records every message, along with its target.
+struct TestLogger;
+
+static RECORDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+static INIT: Once = Once::new();
+
+impl Log for TestLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let entry = (record.target().to_string(), record.args().to_string());
+        RECORDS.lock().expect("lock should not be poisoned").push(entry);
+    }
+
+    fn flush(&self) {}
+}
+
+/// Returns the messages logged to `target`. Each test uses a unique target, as tests run in parallel.
+fn logged(target: &str) -> Vec<String> {
+    INIT.call_once(|| {
+        log::set_logger(&TestLogger).expect("logger should only be set once");
+        log::set_max_level(LevelFilter::Trace);
+    });
+    let records = RECORDS.lock().expect("lock should not be poisoned");
+    records.iter().filter(|(t, _)| t == target).map(|(_, message)| message.clone()).collect()
+}
+
+#[test]
+fn unchanged() {
+    assert!(logged("unchanged").is_empty());
+    let iter = (1..5).log_hint("unchanged");
+    assert_eq!(iter.size_hint(), (4, Some(4)), "size_hint should be unchanged");
+    assert_eq!(iter.size_hint(), (4, Some(4)), "size_hint should be unchanged");
+    assert!(logged("unchanged").is_empty(), "unchanged size hint should not be logged");
+}
+
+#[test]
+fn changed() {
+    assert!(logged("changed").is_empty());
+    let mut iter = (1..5).log_hint("changed");
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(logged("changed"), vec!["size hint changed from (4, Some(4)) to (3, Some(3))"]);
+
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(logged("changed").len(), 2, "each change should be logged once");
+}
+
+#[test]
+fn into_inner() {
+    let iter = LogHint::new(1..5, "into_inner");
+    assert_eq!(iter.target, "into_inner");
+    assert_eq!(iter.into_inner(), 1..5);
+}
@@ -37,6 +37,7 @@ macro_rules! test_iter {
 /// Macro to test initial state/construction of an iterator
 ///
 /// (name, initial => hint: initial_hint)
+/// (name, initial => size_hint: initial_hint)
 /// (name, initial => len: initial_len)
 /// (name, initial => panic: expected_msg)
 macro_rules! test_ctor {
@@ -49,6 +50,14 @@ macro_rules! test_ctor {
         }
     };
 
+    ($name:ident, $iter:expr => size_hint: $hint:expr) => {
+        #[test]
+        fn $name() {
+            let iter = $iter;
+            assert_eq!(iter.size_hint(), Into::<(usize, Option<usize>)>::into($hint), "expected size_hint to match");
+        }
+    };
+
     ($name:ident, $iter:expr => len: $expected:expr) => {
         #[test]
         fn $name() {
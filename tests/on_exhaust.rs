@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use std::ops::Range;
+
+use size_hinter::{OnExhaust, SizeHinter};
+
+const TEST_ITER: Range<usize> = 1..3;
+
+#[test]
+fn initial_state() {
+    let fired = Cell::new(0);
+    let iter = TEST_ITER.on_exhaust(|| fired.set(fired.get() + 1));
+    assert_eq!(iter.size_hint(), (2, Some(2)), "size_hint should be unchanged");
+    assert_eq!(iter.len(), 2, "len should be unchanged");
+    assert!(!iter.fired());
+    assert!(matches!(iter.into_inner(), Range { .. }));
+    assert_eq!(fired.get(), 0, "callback should not fire when unwrapped");
+}
+
+#[test]
+fn fires_at_exhaustion() {
+    let fired = Cell::new(0);
+    let mut iter = TEST_ITER.on_exhaust(|| fired.set(fired.get() + 1));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(fired.get(), 0, "callback should not fire before exhaustion");
+    assert_eq!(iter.next(), None);
+    assert_eq!(fired.get(), 1, "callback should fire at exhaustion");
+    assert!(iter.fired());
+}
+
+#[test]
+fn fires_once() {
+    let fired = Cell::new(0);
+    let mut iter = TEST_ITER.on_exhaust(|| fired.set(fired.get() + 1));
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(fired.get(), 1, "callback should fire only once");
+}
+
+#[test]
+fn fires_backward() {
+    let fired = Cell::new(0);
+    let mut iter = OnExhaust::new(TEST_ITER, || fired.set(fired.get() + 1));
+    assert_eq!(iter.by_ref().rev().collect::<Vec<_>>(), vec![2, 1], "elements should be unchanged");
+    assert_eq!(fired.get(), 1, "callback should fire at exhaustion");
+}
+
+#[test]
+fn debug() {
+    let mut iter = TEST_ITER.on_exhaust(|| ());
+    assert_eq!(format!("{iter:?}"), "OnExhaust { iterator: 1..3, fired: false, .. }");
+    iter.by_ref().for_each(drop);
+    assert_eq!(format!("{iter:?}"), "OnExhaust { iterator: 3..3, fired: true, .. }");
+}
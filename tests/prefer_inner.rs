@@ -0,0 +1,43 @@
+mod macros;
+
+use macros::*;
+
+use std::ops::Range;
+
+use size_hinter::*;
+
+const TEST_ITER: Range<usize> = 1..5;
+
+test_ctor!(initial_hint, PreferInner::new(TEST_ITER) => hint: (4, Some(4)));
+test_ctor!(invalid, PreferInner::new(INVALID_UNIT_ITERATOR) => panic: "iterator's size hint should be valid");
+
+test_iter!(
+    take,
+    (1..).take(3).hint_size(1, 8).prefer_inner_when_exact() => hint: (3, Some(3)),
+    next => Some(1), hint: (2, Some(2));
+    next => Some(2), hint: (1, Some(1));
+    next => Some(3), hint: (0, Some(0));
+    next => None::<usize>, hint: (0, Some(0));
+);
+
+test_iter!(
+    tracked_until_exact,
+    (1..3).filter(|_| true).chain(5..7).hint_size(3, 4).prefer_inner_when_exact() => hint: (3, Some(4)),
+    next => Some(1), hint: (2, Some(3));
+    next => Some(2), hint: (2, Some(2));
+    next => Some(5), hint: (1, Some(1));
+    next => Some(6), hint: (0, Some(0));
+);
+
+test_iter!(
+    backward,
+    (1..10).take(3).hint_size(1, 8).prefer_inner_when_exact() => hint: (3, Some(3)),
+    next_back => Some(3), hint: (2, Some(2));
+    next_back => Some(2), hint: (1, Some(1));
+);
+
+#[test]
+fn tracks_hint_size_hint() {
+    let iter = (1..).take(3).hint_size(1, 8).prefer_inner_when_exact();
+    assert_eq!(iter.hint, SizeHint::bounded(1, 8), "should track the HintSize's size hint");
+}
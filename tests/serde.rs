@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+use size_hinter::SizeHint;
+use size_hinter::testing::sample_hints;
+
+#[test]
+fn serialize() {
+    assert_eq!(serde_json::to_string(&SizeHint::bounded(3, 10)).unwrap(), r#"{"lower":3,"upper":10}"#);
+    assert_eq!(serde_json::to_string(&SizeHint::unbounded(5)).unwrap(), r#"{"lower":5,"upper":null}"#);
+}
+
+#[test]
+fn deserialize() {
+    let hint: SizeHint = serde_json::from_str(r#"{"lower":3,"upper":10}"#).expect("hint should be valid");
+    assert_eq!(hint, SizeHint::bounded(3, 10));
+    let hint: SizeHint = serde_json::from_str(r#"{"lower":5,"upper":null}"#).expect("hint should be valid");
+    assert_eq!(hint, SizeHint::unbounded(5));
+}
+
+#[test]
+fn round_trip() {
+    for hint in sample_hints() {
+        let json = serde_json::to_string(&hint).expect("hint should serialize");
+        assert_eq!(serde_json::from_str::<SizeHint>(&json).expect("hint should deserialize"), hint, "{json}");
+    }
+}
+
+#[test]
+fn deserialize_invalid() {
+    let err = serde_json::from_str::<SizeHint>(r#"{"lower":10,"upper":5}"#).expect_err("hint should be invalid");
+    assert!(err.to_string().starts_with(&size_hinter::InvalidSizeHint.to_string()), "{err}");
+}
+
+#[test]
+fn deserialize_missing_field() {
+    serde_json::from_str::<SizeHint>(r#"{"upper":5}"#).expect_err("lower should be required");
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+use std::ops::Range;
+use std::sync::atomic::Ordering;
+use std::thread;
+
+use size_hinter::{SharedLen, SizeHinter};
+
+const TEST_ITER: Range<usize> = 1..5;
+
+#[test]
+fn initial_state() {
+    let (iter, remaining) = TEST_ITER.shared_len();
+    assert_eq!(iter.size_hint(), (4, Some(4)), "size_hint should be unchanged");
+    assert_eq!(remaining.load(Ordering::Relaxed), 4, "should start with the initial lower bound");
+    assert!(matches!(iter.into_inner(), Range { .. }));
+}
+
+#[test]
+fn tracks_remaining() {
+    let (mut iter, remaining) = TEST_ITER.shared_len();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(remaining.load(Ordering::Relaxed), 3, "should reflect the remaining lower bound");
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(remaining.load(Ordering::Relaxed), 2, "should reflect the remaining lower bound");
+    iter.by_ref().for_each(drop);
+    assert_eq!(remaining.load(Ordering::Relaxed), 0, "should be 0 once exhausted");
+}
+
+#[test]
+fn tracks_lower_bound() {
+    let mut iter = SharedLen::new(TEST_ITER.filter(|x| x % 2 == 0));
+    let remaining = iter.handle();
+    assert_eq!(remaining.load(Ordering::Relaxed), 0, "should reflect the wrapped lower bound");
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(remaining.load(Ordering::Relaxed), 0, "should reflect the wrapped lower bound");
+}
+
+#[test]
+fn cross_thread() {
+    let (iter, remaining) = TEST_ITER.shared_len();
+    let collected = thread::spawn(move || iter.take(3).collect::<Vec<_>>()).join().expect("thread should not panic");
+    assert_eq!(collected, vec![1, 2, 3], "elements should be unchanged");
+    assert_eq!(remaining.load(Ordering::Relaxed), 1, "should be observable from another thread");
+}
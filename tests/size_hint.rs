@@ -54,11 +54,11 @@ macro_rules! binary_op {
 
 /// Test getter/transform operations on a SizeHint
 macro_rules! transform {
-    ($name:ident, $hint:expr, $method:ident() == $expected:expr) => {
+    ($name:ident, $hint:expr, $method:ident($($arg:expr),* $(,)?) == $expected:expr) => {
         #[test]
         fn $name() {
             let hint = $hint;
-            assert_eq!(hint.$method(), $expected);
+            assert_eq!(hint.$method($($arg),*), $expected);
         }
     };
 }
@@ -68,15 +68,33 @@ mod ctor {
 
     ctor!(new_valid, SizeHint::new(3, Some(10)) => (3, Some(10)));
     ctor!(new_invalid, SizeHint::new(10, Some(5)) => panic "values should describe a valid size hint");
+    ctor!(checked_valid, SizeHint::checked(3, Some(10)) => (3, Some(10)));
+    ctor!(checked_unbounded, SizeHint::checked(3, None) => (3, None));
+    ctor!(checked_invalid, SizeHint::checked(10, Some(5)) => panic "invalid hint: lower 10 > upper 5");
     ctor!(try_bounded_valid, SizeHint::try_bounded(3, 10) => ok(3, Some(10)));
     ctor!(try_bounded_invalid, SizeHint::try_bounded(10, 5) => err(InvalidSizeHint));
     ctor!(bounded_valid, SizeHint::bounded(3, 10) => (3, Some(10)));
     ctor!(bounded_invalid, SizeHint::bounded(10, 5) => panic "values should describe a valid size hint");
     ctor!(default, SizeHint::default() => (0, None));
     ctor!(universal, SizeHint::UNIVERSAL => (0, None));
+    ctor!(empty, SizeHint::EMPTY => (0, Some(0)));
     ctor!(unbounded, SizeHint::unbounded(42) => (42, None));
     ctor!(at_most, SizeHint::at_most(42) => (0, Some(42)));
     ctor!(exact, SizeHint::exact(42) => (42, Some(42)));
+    ctor!(estimate_below_max, SizeHint::estimate(5, 10) => (0, Some(5)));
+    ctor!(estimate_above_max, SizeHint::estimate(20, 10) => (0, Some(10)));
+    ctor!(estimate_at_max, SizeHint::estimate(10, 10) => (0, Some(10)));
+    ctor!(zero_or_one, SizeHint::zero_or_one() => (0, Some(1)));
+    ctor!(from_true, SizeHint::from_bool(true) => (1, Some(1)));
+    ctor!(from_false, SizeHint::from_bool(false) => (0, Some(0)));
+    ctor!(exact_u32, SizeHint::exact_u32(42) => (42, Some(42)));
+    ctor!(exact_u32_max, SizeHint::exact_u32(u32::MAX) => (u32::MAX as usize, Some(u32::MAX as usize)));
+
+    #[test]
+    fn exact_u32_const() {
+        const HINT: SizeHint = SizeHint::exact_u32(7);
+        assert_eq!(HINT, SizeHint::exact(7));
+    }
 }
 
 mod try_from_tuple {
@@ -87,6 +105,18 @@ mod try_from_tuple {
     ctor!(invalid, SizeHint::try_from((10, Some(5))) => err(InvalidSizeHint));
 }
 
+mod of {
+    use super::*;
+
+    ctor!(exact, SizeHint::of(&TestIterator::<()>::exact(5)) => ok(5, Some(5)));
+    ctor!(bounded, SizeHint::of(&TestIterator::<()>::new((3, Some(7)))) => ok(3, Some(7)));
+    ctor!(universal, SizeHint::of(&TestIterator::<()>::UNIVERSAL) => ok(0, None));
+    ctor!(invalid, SizeHint::of(&INVALID_UNIT_ITERATOR) => err(InvalidSizeHint));
+    ctor!(invalid_test_iterator, SizeHint::of(&TestIterator::<()>::INVALID) => err(InvalidSizeHint));
+    ctor!(to_size_hint, TestIterator::<()>::exact(5).to_size_hint() => ok(5, Some(5)));
+    ctor!(to_size_hint_invalid, INVALID_UNIT_ITERATOR.to_size_hint() => err(InvalidSizeHint));
+}
+
 mod try_from_range {
     use super::*;
 
@@ -101,6 +131,161 @@ mod try_from_range {
     ctor!(to, SizeHint::try_from(..8) => ok(0, Some(7)));
     ctor!(to_empty, SizeHint::try_from(..0) => err(InvalidSizeHint));
     ctor!(to_inclusive, SizeHint::from(..=7) => (0, Some(7)));
+    ctor!(from_range_to, SizeHint::from_range_to(8) => ok(0, Some(7)));
+    ctor!(from_range_to_empty, SizeHint::from_range_to(0) => err(InvalidSizeHint));
+
+    #[test]
+    fn from_range_to_const() {
+        const RANGE: std::ops::RangeTo<usize> = ..8;
+        const HINT: Result<SizeHint, InvalidSizeHint> = SizeHint::from_range_to(RANGE.end);
+        assert_eq!(HINT, Ok(SizeHint::at_most(7)));
+    }
+}
+
+mod from_slice {
+    use super::*;
+
+    ctor!(array, SizeHint::from(&[1, 2, 3][..]) => (3, Some(3)));
+    ctor!(vec, SizeHint::from(vec![1, 2, 3].as_slice()) => (3, Some(3)));
+    ctor!(empty, SizeHint::from(&[] as &[i32]) => (0, Some(0)));
+}
+
+mod from_f64_upper {
+    use super::*;
+
+    ctor!(integer, SizeHint::from_f64_upper(5.0) => (0, Some(5)));
+    ctor!(fractional, SizeHint::from_f64_upper(4.2) => (0, Some(5)));
+    ctor!(zero, SizeHint::from_f64_upper(0.0) => (0, Some(0)));
+    ctor!(nan, SizeHint::from_f64_upper(f64::NAN) => (0, Some(0)));
+    ctor!(negative, SizeHint::from_f64_upper(-3.5) => (0, Some(0)));
+    ctor!(negative_infinity, SizeHint::from_f64_upper(f64::NEG_INFINITY) => (0, Some(0)));
+    ctor!(infinity, SizeHint::from_f64_upper(f64::INFINITY) => (0, None));
+    ctor!(overflow, SizeHint::from_f64_upper(1e30) => (0, None));
+}
+
+mod from_range_bounds {
+    use super::*;
+
+    ctor!(range, SizeHint::from_range_bounds(3..8) => ok(3, Some(7)));
+    ctor!(inclusive, SizeHint::from_range_bounds(3..=7) => ok(3, Some(7)));
+    ctor!(from, SizeHint::from_range_bounds(5..) => ok(5, None));
+    ctor!(full, SizeHint::from_range_bounds(..) => ok(0, None));
+    ctor!(to, SizeHint::from_range_bounds(..8) => ok(0, Some(7)));
+    ctor!(to_inclusive, SizeHint::from_range_bounds(..=7) => ok(0, Some(7)));
+    ctor!(excluded_start, SizeHint::from_range_bounds((Bound::Excluded(2), Bound::Included(7))) => ok(3, Some(7)));
+    ctor!(size_hint, SizeHint::from_range_bounds(SizeHint::bounded(3, 7)) => ok(3, Some(7)));
+    ctor!(empty, SizeHint::from_range_bounds(5..5) => err(InvalidSizeHint));
+    ctor!(empty_to, SizeHint::from_range_bounds(..0) => err(InvalidSizeHint));
+    ctor!(inverted, SizeHint::from_range_bounds(10..=5) => err(InvalidSizeHint));
+    ctor!(excluded_start_overflow, SizeHint::from_range_bounds((Bound::Excluded(usize::MAX), Bound::Unbounded)) => err(InvalidSizeHint));
+}
+
+mod as_f64 {
+    use super::*;
+
+    #[cfg(target_pointer_width = "64")]
+    const MAX_EXACT: usize = 1 << 53;
+
+    transform!(bounded, SizeHint::bounded(3, 7), as_f64() == (3.0, Some(7.0)));
+    transform!(unbounded, SizeHint::unbounded(3), as_f64() == (3.0, None));
+    #[cfg(target_pointer_width = "64")]
+    transform!(
+        max_exact,
+        SizeHint::exact(MAX_EXACT),
+        as_f64() == (9_007_199_254_740_992.0, Some(9_007_199_254_740_992.0))
+    );
+    // 2^53 + 1 is not representable, and rounds to 2^53
+    #[cfg(target_pointer_width = "64")]
+    transform!(
+        precision_loss,
+        SizeHint::exact(MAX_EXACT + 1),
+        as_f64() == (9_007_199_254_740_992.0, Some(9_007_199_254_740_992.0))
+    );
+}
+
+mod information {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(5), information() == Some(1.0));
+    transform!(zero, SizeHint::ZERO, information() == Some(1.0));
+    transform!(bounded, SizeHint::bounded(3, 7), information() == Some(0.2));
+    transform!(one_slack, SizeHint::bounded(3, 4), information() == Some(0.5));
+    transform!(unbounded, SizeHint::unbounded(5), information() == None);
+    transform!(universal, SizeHint::UNIVERSAL, information() == None);
+}
+
+mod ffi {
+    use super::*;
+
+    ctor!(from_bounded, SizeHint::from_ffi(3, 7, true) => ok(3, Some(7)));
+    ctor!(from_exact, SizeHint::from_ffi(5, 5, true) => ok(5, Some(5)));
+    ctor!(from_unbounded, SizeHint::from_ffi(3, 0, false) => ok(3, None));
+    ctor!(from_unbounded_ignores_upper, SizeHint::from_ffi(7, 3, false) => ok(7, None));
+    ctor!(from_invalid, SizeHint::from_ffi(7, 3, true) => err(InvalidSizeHint));
+
+    transform!(to_bounded, SizeHint::bounded(3, 7), to_ffi() == (3, 7, true));
+    transform!(to_unbounded, SizeHint::unbounded(3), to_ffi() == (3, 0, false));
+
+    #[test]
+    fn round_trip() {
+        for hint in [SizeHint::bounded(3, 7), SizeHint::exact(0), SizeHint::unbounded(3), SizeHint::UNIVERSAL] {
+            let (lower, upper, has_upper) = hint.to_ffi();
+            assert_eq!(SizeHint::from_ffi(lower, upper, has_upper), Ok(hint));
+        }
+    }
+}
+
+mod pair_with_sentinel {
+    use super::*;
+
+    const SENTINEL: usize = usize::MAX;
+
+    transform!(to_bounded, SizeHint::bounded(3, 7), to_pair_with_sentinel(SENTINEL) == (3, 7));
+    transform!(to_unbounded, SizeHint::unbounded(3), to_pair_with_sentinel(SENTINEL) == (3, SENTINEL));
+    transform!(to_custom, SizeHint::unbounded(3), to_pair_with_sentinel(0) == (3, 0));
+
+    ctor!(from_bounded, SizeHint::from_pair_with_sentinel(3, 7, SENTINEL) => ok(3, Some(7)));
+    ctor!(from_unbounded, SizeHint::from_pair_with_sentinel(3, SENTINEL, SENTINEL) => ok(3, None));
+    ctor!(from_custom, SizeHint::from_pair_with_sentinel(3, 0, 0) => ok(3, None));
+    ctor!(from_invalid, SizeHint::from_pair_with_sentinel(7, 3, SENTINEL) => err(InvalidSizeHint));
+
+    #[test]
+    fn round_trip() {
+        for hint in [SizeHint::bounded(3, 7), SizeHint::exact(0), SizeHint::unbounded(3), SizeHint::UNIVERSAL] {
+            let (lower, upper) = hint.to_pair_with_sentinel(SENTINEL);
+            assert_eq!(SizeHint::from_pair_with_sentinel(lower, upper, SENTINEL), Ok(hint));
+        }
+    }
+
+    #[test]
+    fn sentinel_collision() {
+        let (lower, upper) = SizeHint::at_most(SENTINEL).to_pair_with_sentinel(SENTINEL);
+        let hint = SizeHint::from_pair_with_sentinel(lower, upper, SENTINEL);
+        assert_eq!(hint, Ok(SizeHint::UNIVERSAL), "upper equal to the sentinel should become unbounded");
+    }
+}
+
+mod le_bytes {
+    use super::*;
+
+    const BOUNDED: [u8; 17] = [3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 1];
+    const UNBOUNDED: [u8; 17] = [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    transform!(to_bounded, SizeHint::bounded(3, 7), to_le_bytes() == BOUNDED);
+    transform!(to_unbounded, SizeHint::unbounded(3), to_le_bytes() == UNBOUNDED);
+
+    ctor!(from_bounded, SizeHint::from_le_bytes(BOUNDED) => ok(3, Some(7)));
+    ctor!(from_unbounded, SizeHint::from_le_bytes(UNBOUNDED) => ok(3, None));
+    ctor!(from_invalid_flag, SizeHint::from_le_bytes([2; 17]) => err(InvalidSizeHint));
+    ctor!(from_inverted, SizeHint::from_le_bytes(SizeHint::bounded(3, 7).to_le_bytes().map(|b| if b == 3 { 9 } else { b })) => err(InvalidSizeHint));
+
+    #[test]
+    fn round_trip() {
+        for hint in [SizeHint::bounded(3, 7), SizeHint::exact(usize::MAX), SizeHint::unbounded(3), SizeHint::UNIVERSAL]
+        {
+            assert_eq!(SizeHint::from_le_bytes(hint.to_le_bytes()), Ok(hint));
+        }
+    }
 }
 
 mod decrement {
@@ -113,6 +298,219 @@ mod decrement {
     transform!(universal, SizeHint::UNIVERSAL, decrement() == SizeHint::UNIVERSAL);
 }
 
+mod is_empty {
+    use super::*;
+
+    transform!(empty, SizeHint::EMPTY, is_empty() == true);
+    transform!(exact_zero, SizeHint::exact(0), is_empty() == true);
+    transform!(at_most, SizeHint::at_most(5), is_empty() == false);
+    transform!(exact, SizeHint::exact(5), is_empty() == false);
+    transform!(universal, SizeHint::UNIVERSAL, is_empty() == false);
+}
+
+mod exact_len {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(5), exact_len() == Some(5));
+    transform!(zero, SizeHint::ZERO, exact_len() == Some(0));
+    transform!(bounded, SizeHint::bounded(3, 5), exact_len() == None);
+    transform!(unbounded, SizeHint::unbounded(5), exact_len() == None);
+    transform!(unbounded_max, SizeHint::unbounded(usize::MAX), exact_len() == None);
+}
+
+mod is_exact {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(5), is_exact() == true);
+    transform!(bounded, SizeHint::bounded(3, 5), is_exact() == false);
+    transform!(unbounded, SizeHint::unbounded(5), is_exact() == false);
+}
+
+mod expect_exact {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(5), expect_exact("should be exact") == 5);
+    transform!(zero, SizeHint::ZERO, expect_exact("should be exact") == 0);
+    ctor!(bounded, SizeHint::bounded(3, 5).expect_exact("custom message") => panic "custom message");
+    ctor!(unbounded, SizeHint::unbounded(5).expect_exact("custom message") => panic "custom message");
+}
+
+mod saturating_sub {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(5, 9), saturating_sub(3) == (2, Some(6)));
+    transform!(past_lower, SizeHint::bounded(5, 9), saturating_sub(7) == (0, Some(2)));
+    transform!(past_upper, SizeHint::bounded(5, 9), saturating_sub(10) == (0, Some(0)));
+    transform!(unbounded, SizeHint::unbounded(5), saturating_sub(3) == (2, None));
+    transform!(zero, SizeHint::bounded(5, 9), saturating_sub(0) == (5, Some(9)));
+}
+
+mod checked_decrement {
+    use super::*;
+
+    transform!(exact_one, SizeHint::exact(1), checked_decrement() == Some(SizeHint::exact(0)));
+    transform!(exact_zero, SizeHint::exact(0), checked_decrement() == None);
+    transform!(bounded, SizeHint::bounded(5, 10), checked_decrement() == Some(SizeHint::bounded(4, 9)));
+    transform!(saturating_lower, SizeHint::at_most(5), checked_decrement() == Some(SizeHint::at_most(4)));
+    transform!(unbounded_zero, SizeHint::unbounded(0), checked_decrement() == Some(SizeHint::unbounded(0)));
+    transform!(unbounded, SizeHint::unbounded(10), checked_decrement() == Some(SizeHint::unbounded(9)));
+}
+
+mod add_lower {
+    use super::*;
+
+    ctor!(within, SizeHint::bounded(2, 10).add_lower(3) => ok(5, Some(10)));
+    ctor!(to_upper, SizeHint::bounded(2, 5).add_lower(3) => ok(5, Some(5)));
+    ctor!(beyond_upper, SizeHint::bounded(2, 4).add_lower(3) => err(InvalidSizeHint));
+    ctor!(unbounded, SizeHint::unbounded(2).add_lower(3) => ok(5, None));
+    ctor!(saturating, SizeHint::unbounded(2).add_lower(usize::MAX) => ok(usize::MAX, None));
+}
+
+mod at_least_nonempty {
+    use super::*;
+
+    ctor!(raised, SizeHint::at_most(5).at_least_nonempty() => ok(1, Some(5)));
+    ctor!(unchanged, SizeHint::bounded(3, 5).at_least_nonempty() => ok(3, Some(5)));
+    ctor!(exact_one, SizeHint::zero_or_one().at_least_nonempty() => ok(1, Some(1)));
+    ctor!(unbounded, SizeHint::UNIVERSAL.at_least_nonempty() => ok(1, None));
+    ctor!(empty, SizeHint::ZERO.at_least_nonempty() => err(InvalidSizeHint));
+}
+
+mod add_upper {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(2, 4), add_upper(3) == (2, Some(7)));
+    transform!(unbounded, SizeHint::unbounded(2), add_upper(3) == (2, None));
+    transform!(saturating, SizeHint::bounded(2, 4), add_upper(usize::MAX) == (2, Some(usize::MAX)));
+}
+
+mod windowed {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(10), windowed(3) == (8, Some(8)));
+    transform!(too_short, SizeHint::exact(2), windowed(5) == (0, Some(0)));
+    transform!(window_of_one, SizeHint::bounded(3, 7), windowed(1) == (3, Some(7)));
+    transform!(bounded, SizeHint::bounded(3, 7), windowed(4) == (0, Some(4)));
+    transform!(unbounded, SizeHint::unbounded(5), windowed(3) == (3, None));
+    ctor!(zero_window, SizeHint::exact(5).windowed(0) => panic "window size should be non-zero");
+}
+
+mod next_power_of_two_upper {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(3, 5), next_power_of_two_upper() == Some(8));
+    transform!(power_of_two, SizeHint::exact(8), next_power_of_two_upper() == Some(8));
+    transform!(one, SizeHint::at_most(1), next_power_of_two_upper() == Some(1));
+    transform!(zero, SizeHint::ZERO, next_power_of_two_upper() == None);
+    transform!(unbounded, SizeHint::unbounded(3), next_power_of_two_upper() == None);
+    transform!(overflow, SizeHint::at_most(usize::MAX), next_power_of_two_upper() == None);
+}
+
+mod growth_reallocations {
+    use super::*;
+
+    transform!(bounded, SizeHint::at_most(100), growth_reallocations(8) == Some(4));
+    transform!(fits, SizeHint::bounded(3, 8), growth_reallocations(8) == Some(0));
+    transform!(exact_power, SizeHint::exact(64), growth_reallocations(8) == Some(3));
+    transform!(zero_cap, SizeHint::at_most(4), growth_reallocations(0) == Some(2));
+    transform!(zero_upper, SizeHint::ZERO, growth_reallocations(0) == Some(0));
+    transform!(saturating, SizeHint::at_most(usize::MAX), growth_reallocations(1) == Some(usize::BITS));
+    transform!(unbounded, SizeHint::unbounded(3), growth_reallocations(8) == None);
+}
+
+mod scale {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(2, 5), scale(3) == (6, Some(15)));
+    transform!(unbounded, SizeHint::unbounded(2), scale(3) == (6, None));
+    transform!(by_one, SizeHint::bounded(2, 5), scale(1) == (2, Some(5)));
+    transform!(zero, SizeHint::bounded(2, 5), scale(0) == SizeHint::ZERO);
+    transform!(zero_unbounded, SizeHint::UNIVERSAL, scale(0) == SizeHint::ZERO);
+    transform!(overflow, SizeHint::bounded(2, usize::MAX / 2 + 1), scale(2) == (4, Some(usize::MAX)));
+    transform!(overflow_unbounded, SizeHint::unbounded(usize::MAX), scale(2) == (usize::MAX, None));
+
+    #[test]
+    fn operator() {
+        assert_eq!(SizeHint::bounded(2, 5) * 3, (6, Some(15)));
+        assert_eq!(SizeHint::unbounded(2) * 3, (6, None));
+        assert_eq!(SizeHint::exact(3) * 2, (6, Some(6)));
+        assert_eq!(SizeHint::at_most(usize::MAX) * 2, (0, Some(usize::MAX)));
+    }
+}
+
+mod try_scale {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(2, 5), try_scale(3) == Ok(SizeHint::bounded(6, 15)));
+    transform!(unbounded, SizeHint::unbounded(2), try_scale(3) == Ok(SizeHint::unbounded(6)));
+    transform!(zero, SizeHint::UNIVERSAL, try_scale(0) == Ok(SizeHint::ZERO));
+    transform!(zero_max, SizeHint::exact(usize::MAX), try_scale(0) == Ok(SizeHint::ZERO));
+    transform!(max, SizeHint::exact(usize::MAX), try_scale(1) == Ok(SizeHint::exact(usize::MAX)));
+    transform!(
+        lower_overflow,
+        SizeHint::bounded(usize::MAX / 2 + 1, usize::MAX),
+        try_scale(2) == Err(ScaleOverflow::Lower)
+    );
+    transform!(lower_overflow_unbounded, SizeHint::unbounded(usize::MAX), try_scale(2) == Err(ScaleOverflow::Lower));
+    transform!(upper_overflow, SizeHint::bounded(1, usize::MAX / 2 + 1), try_scale(2) == Err(ScaleOverflow::Upper));
+}
+
+mod repeat {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(3), repeat(4) == (12, Some(12)));
+    transform!(bounded, SizeHint::bounded(2, 5), repeat(3) == (6, Some(15)));
+    transform!(unbounded, SizeHint::unbounded(3), repeat(2) == (6, None));
+    transform!(once, SizeHint::bounded(2, 5), repeat(1) == (2, Some(5)));
+    transform!(zero_times, SizeHint::unbounded(3), repeat(0) == SizeHint::ZERO);
+    transform!(saturating, SizeHint::bounded(2, usize::MAX / 2 + 1), repeat(2) == (4, Some(usize::MAX)));
+}
+
+mod split_first {
+    use super::*;
+
+    transform!(non_empty, SizeHint::bounded(2, 5), split_first() == (SizeHint::exact(1), SizeHint::bounded(1, 4)));
+    transform!(exact_one, SizeHint::exact(1), split_first() == (SizeHint::exact(1), SizeHint::ZERO));
+    transform!(possibly_empty, SizeHint::at_most(5), split_first() == (SizeHint::at_most(1), SizeHint::at_most(4)));
+    transform!(unbounded, SizeHint::UNIVERSAL, split_first() == (SizeHint::at_most(1), SizeHint::UNIVERSAL));
+    transform!(empty, SizeHint::ZERO, split_first() == (SizeHint::ZERO, SizeHint::ZERO));
+}
+
+mod div_floor {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(7, 10), div_floor(3) == (2, Some(3)));
+    transform!(exact_multiple, SizeHint::exact(9), div_floor(3) == (3, Some(3)));
+    transform!(unbounded, SizeHint::unbounded(7), div_floor(3) == (2, None));
+    transform!(smaller_than_divisor, SizeHint::at_most(2), div_floor(3) == (0, Some(0)));
+    transform!(by_one, SizeHint::bounded(7, 10), div_floor(1) == (7, Some(10)));
+    ctor!(zero_divisor, SizeHint::exact(5).div_floor(0) => panic "divisor should be non-zero");
+}
+
+mod has_partial_chunk {
+    use super::*;
+
+    transform!(partial, SizeHint::exact(10), has_partial_chunk(3) == Some(true));
+    transform!(whole, SizeHint::exact(9), has_partial_chunk(3) == Some(false));
+    transform!(smaller_than_chunk, SizeHint::exact(2), has_partial_chunk(3) == Some(true));
+    transform!(empty, SizeHint::ZERO, has_partial_chunk(3) == Some(false));
+    transform!(bounded, SizeHint::bounded(3, 10), has_partial_chunk(3) == None);
+    transform!(unbounded, SizeHint::unbounded(9), has_partial_chunk(3) == None);
+    transform!(zero_chunk, SizeHint::exact(9), has_partial_chunk(0) == None);
+}
+
+mod nonzero {
+    use super::*;
+
+    use std::num::NonZeroUsize;
+
+    transform!(lower_zero, SizeHint::at_most(10), lower_nonzero() == None);
+    transform!(lower_positive, SizeHint::bounded(5, 10), lower_nonzero() == NonZeroUsize::new(5));
+    transform!(upper_zero, SizeHint::ZERO, upper_nonzero() == None);
+    transform!(upper_positive, SizeHint::bounded(5, 10), upper_nonzero() == NonZeroUsize::new(10));
+    transform!(upper_unbounded, SizeHint::unbounded(5), upper_nonzero() == None);
+}
+
 mod properties {
     use super::*;
 
@@ -148,6 +546,217 @@ mod disjoint {
     binary_op!(both_unbounded, disjoint, SizeHint::unbounded(5), SizeHint::unbounded(10) => false, false);
 }
 
+mod max_upper {
+    use super::*;
+
+    binary_op!(bounded, max_upper, SizeHint::bounded(2, 5), SizeHint::bounded(3, 8) => Some(8), Some(8));
+    binary_op!(equal, max_upper, SizeHint::bounded(2, 5), SizeHint::exact(5) => Some(5), Some(5));
+    binary_op!(unbounded, max_upper, SizeHint::bounded(2, 5), SizeHint::unbounded(3) => None, None);
+    binary_op!(both_unbounded, max_upper, SizeHint::unbounded(2), SizeHint::unbounded(3) => None, None);
+}
+
+mod min_upper {
+    use super::*;
+
+    binary_op!(bounded, min_upper, SizeHint::bounded(2, 5), SizeHint::bounded(3, 8) => Some(5), Some(5));
+    binary_op!(equal, min_upper, SizeHint::bounded(2, 5), SizeHint::exact(5) => Some(5), Some(5));
+    binary_op!(unbounded, min_upper, SizeHint::bounded(2, 5), SizeHint::unbounded(3) => Some(5), Some(5));
+    binary_op!(both_unbounded, min_upper, SizeHint::unbounded(2), SizeHint::unbounded(3) => None, None);
+}
+
+mod tighter_upper_than {
+    use super::*;
+
+    binary_op!(bounded, tighter_upper_than, SizeHint::bounded(2, 5), SizeHint::bounded(2, 8) => true, false);
+    binary_op!(unbounded, tighter_upper_than, SizeHint::bounded(2, 5), SizeHint::unbounded(2) => true, false);
+    binary_op!(equal, tighter_upper_than, SizeHint::bounded(2, 5), SizeHint::bounded(3, 5) => false, false);
+    binary_op!(both_unbounded, tighter_upper_than, SizeHint::unbounded(2), SizeHint::unbounded(3) => false, false);
+}
+
+mod tighter_lower_than {
+    use super::*;
+
+    binary_op!(larger, tighter_lower_than, SizeHint::bounded(5, 8), SizeHint::bounded(3, 8) => true, false);
+    binary_op!(equal, tighter_lower_than, SizeHint::bounded(3, 8), SizeHint::unbounded(3) => false, false);
+    binary_op!(unbounded, tighter_lower_than, SizeHint::unbounded(5), SizeHint::exact(3) => true, false);
+}
+
+mod lower_eq {
+    use super::*;
+
+    transform!(bounded, SizeHint::bounded(5, 10), lower_eq(5) == true);
+    transform!(unbounded, SizeHint::unbounded(5), lower_eq(5) == true);
+    transform!(exact, SizeHint::exact(5), lower_eq(5) == true);
+    transform!(different, SizeHint::bounded(4, 10), lower_eq(5) == false);
+}
+
+mod intersect {
+    use super::*;
+
+    binary_op!(partial, intersect, SizeHint::bounded(3, 6), SizeHint::bounded(5, 10) => Some(SizeHint::bounded(5, 6)), Some(SizeHint::bounded(5, 6)));
+    binary_op!(contained, intersect, SizeHint::bounded(4, 6), SizeHint::bounded(3, 10) => Some(SizeHint::bounded(4, 6)), Some(SizeHint::bounded(4, 6)));
+    binary_op!(touching, intersect, SizeHint::bounded(3, 6), SizeHint::bounded(6, 10) => Some(SizeHint::exact(6)), Some(SizeHint::exact(6)));
+    binary_op!(unbounded, intersect, SizeHint::unbounded(5), SizeHint::bounded(3, 10) => Some(SizeHint::bounded(5, 10)), Some(SizeHint::bounded(5, 10)));
+    binary_op!(both_unbounded, intersect, SizeHint::unbounded(5), SizeHint::unbounded(10) => Some(SizeHint::unbounded(10)), Some(SizeHint::unbounded(10)));
+    binary_op!(universal, intersect, SizeHint::UNIVERSAL, SizeHint::bounded(3, 10) => Some(SizeHint::bounded(3, 10)), Some(SizeHint::bounded(3, 10)));
+    binary_op!(disjoint, intersect, SizeHint::bounded(3, 6), SizeHint::bounded(7, 10) => None, None);
+    binary_op!(disjoint_unbounded, intersect, SizeHint::exact(5), SizeHint::unbounded(10) => None, None);
+}
+
+mod union {
+    use super::*;
+
+    binary_op!(overlapping, union, SizeHint::bounded(3, 6), SizeHint::bounded(5, 10) => SizeHint::bounded(3, 10), SizeHint::bounded(3, 10));
+    binary_op!(disjoint, union, SizeHint::bounded(3, 4), SizeHint::bounded(7, 10) => SizeHint::bounded(3, 10), SizeHint::bounded(3, 10));
+    binary_op!(contained, union, SizeHint::bounded(4, 6), SizeHint::bounded(3, 10) => SizeHint::bounded(3, 10), SizeHint::bounded(3, 10));
+    binary_op!(unbounded, union, SizeHint::exact(2), SizeHint::unbounded(10) => SizeHint::unbounded(2), SizeHint::unbounded(2));
+    binary_op!(both_unbounded, union, SizeHint::unbounded(5), SizeHint::unbounded(10) => SizeHint::unbounded(5), SizeHint::unbounded(5));
+    binary_op!(universal_bounded, union, SizeHint::UNIVERSAL, SizeHint::bounded(3, 10) => SizeHint::UNIVERSAL, SizeHint::UNIVERSAL);
+    binary_op!(universal_unbounded, union, SizeHint::UNIVERSAL, SizeHint::unbounded(3) => SizeHint::UNIVERSAL, SizeHint::UNIVERSAL);
+}
+
+mod add {
+    use super::*;
+
+    binary_op!(bounded, saturating_add, SizeHint::bounded(2, 3), SizeHint::bounded(4, 5) => (6, Some(8)), (6, Some(8)));
+    binary_op!(unbounded, saturating_add, SizeHint::unbounded(2), SizeHint::bounded(4, 5) => (6, None), (6, None));
+    binary_op!(both_unbounded, saturating_add, SizeHint::unbounded(2), SizeHint::unbounded(4) => (6, None), (6, None));
+    binary_op!(zero, saturating_add, SizeHint::ZERO, SizeHint::bounded(4, 5) => (4, Some(5)), (4, Some(5)));
+    binary_op!(overflow, saturating_add, SizeHint::bounded(usize::MAX - 1, usize::MAX), SizeHint::exact(2) => (usize::MAX, Some(usize::MAX)), (usize::MAX, Some(usize::MAX)));
+
+    #[test]
+    fn operator() {
+        assert_eq!(SizeHint::bounded(2, 3) + SizeHint::bounded(4, 5), (6, Some(8)));
+        assert_eq!(SizeHint::unbounded(2) + SizeHint::bounded(4, 5), (6, None));
+        assert_eq!(SizeHint::unbounded(usize::MAX) + SizeHint::exact(1), (usize::MAX, None));
+    }
+}
+
+mod intersection_or_empty {
+    use super::*;
+
+    binary_op!(partial, intersection_or_empty, SizeHint::bounded(3, 6), SizeHint::bounded(5, 10) => SizeHint::bounded(5, 6), SizeHint::bounded(5, 6));
+    binary_op!(contained, intersection_or_empty, SizeHint::bounded(4, 6), SizeHint::bounded(3, 10) => SizeHint::bounded(4, 6), SizeHint::bounded(4, 6));
+    binary_op!(touching, intersection_or_empty, SizeHint::bounded(3, 6), SizeHint::bounded(6, 10) => SizeHint::exact(6), SizeHint::exact(6));
+    binary_op!(unbounded, intersection_or_empty, SizeHint::unbounded(5), SizeHint::bounded(3, 10) => SizeHint::bounded(5, 10), SizeHint::bounded(5, 10));
+    binary_op!(both_unbounded, intersection_or_empty, SizeHint::unbounded(5), SizeHint::unbounded(10) => SizeHint::unbounded(10), SizeHint::unbounded(10));
+    binary_op!(disjoint, intersection_or_empty, SizeHint::bounded(3, 6), SizeHint::bounded(7, 10) => SizeHint::ZERO, SizeHint::ZERO);
+    binary_op!(disjoint_unbounded, intersection_or_empty, SizeHint::exact(5), SizeHint::unbounded(10) => SizeHint::ZERO, SizeHint::ZERO);
+}
+
+mod best_of {
+    use super::*;
+
+    binary_op!(contained, best_of, SizeHint::bounded(3, 10), SizeHint::bounded(5, 8) => Ok(SizeHint::bounded(5, 8)), Ok(SizeHint::bounded(5, 8)));
+    binary_op!(partial, best_of, SizeHint::bounded(3, 10), SizeHint::bounded(5, 12) => Ok(SizeHint::bounded(5, 10)), Ok(SizeHint::bounded(5, 10)));
+    binary_op!(unbounded, best_of, SizeHint::unbounded(5), SizeHint::bounded(3, 10) => Ok(SizeHint::bounded(5, 10)), Ok(SizeHint::bounded(5, 10)));
+    binary_op!(contradictory, best_of, SizeHint::bounded(6, 10), SizeHint::bounded(0, 4) => Err(InvalidSizeHint), Err(InvalidSizeHint));
+}
+
+mod clamp {
+    use super::*;
+
+    transform!(unbounded, SizeHint::unbounded(2), clamp(5, 10) == Ok(SizeHint::bounded(5, 10)));
+    transform!(within, SizeHint::bounded(6, 8), clamp(5, 10) == Ok(SizeHint::bounded(6, 8)));
+    transform!(partial, SizeHint::bounded(3, 12), clamp(5, 10) == Ok(SizeHint::bounded(5, 10)));
+    transform!(touching, SizeHint::unbounded(1), clamp(0, 1) == Ok(SizeHint::exact(1)));
+    transform!(above, SizeHint::exact(12), clamp(5, 10) == Err(InvalidSizeHint));
+    transform!(below, SizeHint::at_most(3), clamp(5, 10) == Err(InvalidSizeHint));
+    transform!(inverted, SizeHint::unbounded(0), clamp(10, 5) == Err(InvalidSizeHint));
+}
+
+mod prefer_exact {
+    use super::*;
+
+    binary_op!(exact_contained, prefer_exact, SizeHint::exact(5), SizeHint::bounded(3, 10) => SizeHint::exact(5), SizeHint::exact(5));
+    binary_op!(exact_unbounded, prefer_exact, SizeHint::exact(5), SizeHint::UNIVERSAL => SizeHint::exact(5), SizeHint::exact(5));
+    binary_op!(both_exact, prefer_exact, SizeHint::exact(5), SizeHint::exact(5) => SizeHint::exact(5), SizeHint::exact(5));
+    binary_op!(ranges, prefer_exact, SizeHint::bounded(3, 6), SizeHint::bounded(5, 10) => SizeHint::bounded(5, 6), SizeHint::bounded(5, 6));
+    binary_op!(disjoint_exact, prefer_exact, SizeHint::exact(5), SizeHint::exact(6) => SizeHint::exact(5), SizeHint::exact(6));
+    binary_op!(disjoint, prefer_exact, SizeHint::exact(5), SizeHint::unbounded(10) => SizeHint::exact(5), SizeHint::unbounded(10));
+}
+
+mod combine {
+    use super::*;
+
+    const A: SizeHint = SizeHint::bounded(2, 6);
+    const B: SizeHint = SizeHint::bounded(4, 10);
+
+    transform!(union, A, combine(B, CombineOp::Union) == Some(SizeHint::bounded(2, 10)));
+    transform!(intersection, A, combine(B, CombineOp::Intersection) == Some(SizeHint::bounded(4, 6)));
+    transform!(sum, A, combine(B, CombineOp::Sum) == Some(SizeHint::bounded(6, 16)));
+    transform!(zip, A, combine(B, CombineOp::Zip) == Some(SizeHint::bounded(2, 6)));
+
+    const C: SizeHint = SizeHint::unbounded(8);
+
+    transform!(union_unbounded, A, combine(C, CombineOp::Union) == Some(SizeHint::unbounded(2)));
+    transform!(intersection_disjoint, A, combine(C, CombineOp::Intersection) == None);
+    transform!(sum_unbounded, A, combine(C, CombineOp::Sum) == Some(SizeHint::unbounded(10)));
+    transform!(zip_unbounded, A, combine(C, CombineOp::Zip) == Some(A));
+    transform!(
+        sum_saturating,
+        SizeHint::exact(usize::MAX),
+        combine(A, CombineOp::Sum) == Some(SizeHint::exact(usize::MAX))
+    );
+}
+
+mod fold_bounds {
+    use super::*;
+
+    const HINTS: [SizeHint; 3] = [SizeHint::bounded(2, 6), SizeHint::bounded(4, 10), SizeHint::unbounded(3)];
+
+    fn max_upper(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        a.zip(b).map(|(a, b)| a.max(b))
+    }
+
+    fn min_upper(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (upper, None) | (None, upper) => upper,
+        }
+    }
+
+    #[test]
+    fn min_lower_max_upper() {
+        assert_eq!(SizeHint::fold_bounds(HINTS, usize::min, max_upper), Some(SizeHint::unbounded(2)));
+        assert_eq!(
+            SizeHint::fold_bounds(HINTS[..2].iter().copied(), usize::min, max_upper),
+            Some(SizeHint::bounded(2, 10))
+        );
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(SizeHint::fold_bounds([SizeHint::exact(3)], usize::min, max_upper), Some(SizeHint::exact(3)));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(SizeHint::fold_bounds([], usize::min, max_upper), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "values should describe a valid size hint")]
+    fn invalid() {
+        let _ = SizeHint::fold_bounds([SizeHint::exact(2), SizeHint::unbounded(5)], usize::max, min_upper);
+    }
+}
+
+mod admits {
+    use super::*;
+
+    transform!(below_lower, SizeHint::bounded(3, 6), admits(2) == false);
+    transform!(lower, SizeHint::bounded(3, 6), admits(3) == true);
+    transform!(within, SizeHint::bounded(3, 6), admits(4) == true);
+    transform!(upper, SizeHint::bounded(3, 6), admits(6) == true);
+    transform!(above_upper, SizeHint::bounded(3, 6), admits(7) == false);
+    transform!(exact, SizeHint::exact(5), admits(5) == true);
+    transform!(zero, SizeHint::ZERO, admits(0) == true);
+    transform!(unbounded_lower, SizeHint::unbounded(3), admits(3) == true);
+    transform!(unbounded_max, SizeHint::unbounded(3), admits(usize::MAX) == true);
+    transform!(unbounded_below, SizeHint::unbounded(3), admits(2) == false);
+}
+
 mod subset_of {
     use super::*;
 
@@ -159,6 +768,51 @@ mod subset_of {
     binary_op!(unbounded_not_in_bounded, subset_of, SizeHint::unbounded(5), SizeHint::bounded(3, 10) => false, false);
 }
 
+mod contains_range {
+    use super::*;
+
+    transform!(inclusive, SizeHint::bounded(2, 10), contains_range(3..=7) == true);
+    transform!(equal, SizeHint::bounded(2, 10), contains_range(2..=10) == true);
+    transform!(exclusive, SizeHint::bounded(2, 10), contains_range(2..11) == true);
+    transform!(lower_too_small, SizeHint::bounded(2, 10), contains_range(1..5) == false);
+    transform!(upper_too_large, SizeHint::bounded(2, 10), contains_range(5..=11) == false);
+    transform!(unbounded_range, SizeHint::bounded(2, 10), contains_range(5..) == false);
+    transform!(unbounded_hint, SizeHint::unbounded(2), contains_range(5..) == true);
+    transform!(full, SizeHint::UNIVERSAL, contains_range(..) == true);
+    transform!(empty, SizeHint::exact(5), contains_range(7..7) == true);
+}
+
+mod try_into_range {
+    use super::*;
+
+    use std::ops::Range;
+
+    #[test]
+    fn bounded() {
+        assert_eq!(Range::try_from(SizeHint::bounded(3, 7)), Ok(3..8));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(Range::try_from(SizeHint::exact(5)), Ok(5..6));
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Range::try_from(SizeHint::ZERO), Ok(0..1));
+    }
+
+    #[test]
+    fn unbounded() {
+        assert_eq!(Range::try_from(SizeHint::unbounded(3)), Err(InvalidSizeHint));
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Range::try_from(SizeHint::at_most(usize::MAX)), Err(InvalidSizeHint));
+    }
+}
+
 mod into_tuple {
     use super::*;
 
@@ -212,3 +866,53 @@ mod accessors {
         assert_eq!(UPPER, Some(20));
     }
 }
+
+mod capacity {
+    use super::*;
+
+    transform!(lower_bounded, SizeHint::bounded(3, 10), capacity(CapacityPolicy::Lower) == 3);
+    transform!(lower_unbounded, SizeHint::unbounded(5), capacity(CapacityPolicy::Lower) == 5);
+    transform!(upper_bounded, SizeHint::bounded(3, 10), capacity(CapacityPolicy::Upper) == 10);
+    transform!(upper_unbounded, SizeHint::unbounded(5), capacity(CapacityPolicy::Upper) == 5);
+    transform!(midpoint_bounded, SizeHint::bounded(3, 10), capacity(CapacityPolicy::Midpoint) == 6);
+    transform!(midpoint_unbounded, SizeHint::unbounded(5), capacity(CapacityPolicy::Midpoint) == 5);
+    transform!(
+        midpoint_max,
+        SizeHint::bounded(usize::MAX - 2, usize::MAX),
+        capacity(CapacityPolicy::Midpoint) == usize::MAX - 1
+    );
+    transform!(upper_or_bounded, SizeHint::bounded(3, 10), capacity(CapacityPolicy::UpperOr(20)) == 10);
+    transform!(upper_or_unbounded, SizeHint::unbounded(5), capacity(CapacityPolicy::UpperOr(20)) == 20);
+    transform!(upper_or_below_lower, SizeHint::unbounded(5), capacity(CapacityPolicy::UpperOr(2)) == 5);
+}
+
+mod grow_step {
+    use super::*;
+
+    transform!(below, SizeHint::exact(10), grow_step(4) == 6);
+    transform!(above, SizeHint::exact(10), grow_step(12) == 0);
+    transform!(uses_lower, SizeHint::bounded(3, 10), grow_step(1) == 2);
+    transform!(zero, SizeHint::ZERO, grow_step(0) == 0);
+}
+
+mod grow_step_with {
+    use super::*;
+
+    transform!(upper_below, SizeHint::bounded(3, 10), grow_step_with(4, CapacityPolicy::Upper) == 6);
+    transform!(upper_equal, SizeHint::bounded(3, 10), grow_step_with(10, CapacityPolicy::Upper) == 0);
+    transform!(upper_above, SizeHint::bounded(3, 10), grow_step_with(12, CapacityPolicy::Upper) == 0);
+    transform!(lower, SizeHint::bounded(3, 10), grow_step_with(1, CapacityPolicy::Lower) == 2);
+    transform!(unbounded, SizeHint::unbounded(5), grow_step_with(0, CapacityPolicy::UpperOr(20)) == 20);
+    transform!(zero, SizeHint::ZERO, grow_step_with(0, CapacityPolicy::Upper) == 0);
+}
+
+mod display {
+    use super::*;
+
+    transform!(exact, SizeHint::exact(5), to_string() == "5");
+    transform!(zero, SizeHint::ZERO, to_string() == "0");
+    transform!(bounded, SizeHint::bounded(3, 10), to_string() == "3..=10");
+    transform!(at_most, SizeHint::at_most(10), to_string() == "0..=10");
+    transform!(unbounded, SizeHint::unbounded(5), to_string() == "5..");
+    transform!(universal, SizeHint::UNIVERSAL, to_string() == "..");
+}
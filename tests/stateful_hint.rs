@@ -0,0 +1,52 @@
+use std::ops::Range;
+
+use size_hinter::{SizeHint, SizeHinter, StatefulHint};
+
+const TEST_ITER: Range<usize> = 1..4;
+
+fn by_two(hint: SizeHint) -> SizeHint {
+    hint.saturating_sub(2)
+}
+
+#[test]
+fn initial_state() {
+    let iter = TEST_ITER.hint_each(SizeHint::bounded(4, 6), by_two);
+    assert_eq!(iter.size_hint(), (4, Some(6)), "size_hint should be the initial hint");
+    assert_eq!(iter.hint, SizeHint::bounded(4, 6));
+    assert!(matches!(iter.into_inner(), Range { .. }));
+}
+
+#[test]
+fn updates_forward() {
+    let mut iter = TEST_ITER.hint_each(SizeHint::bounded(4, 6), by_two);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (2, Some(4)), "hint should shrink by 2");
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.size_hint(), (0, Some(2)), "hint should shrink by 2");
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.size_hint(), (0, Some(0)), "hint should saturate at 0");
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)), "hint should be zero once exhausted");
+}
+
+#[test]
+fn updates_backward() {
+    let mut iter = StatefulHint::new(TEST_ITER, SizeHint::unbounded(6), by_two);
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.size_hint(), (4, None), "hint should shrink by 2");
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.size_hint(), (2, None), "hint should shrink by 2");
+}
+
+#[test]
+fn exhaustion_resets_hint() {
+    let mut iter = StatefulHint::new(std::iter::empty::<usize>(), SizeHint::unbounded(5), by_two);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)), "hint should be zero once exhausted");
+}
+
+#[test]
+fn debug() {
+    let iter = TEST_ITER.hint_each(SizeHint::exact(3), by_two);
+    assert_eq!(format!("{iter:?}"), format!("StatefulHint {{ iterator: 1..4, hint: {:?}, .. }}", SizeHint::exact(3)));
+}
@@ -28,6 +28,42 @@ fn invalid_size_hint_is_invalid() {
     assert!(lower > upper.unwrap(), "Size hint should be invalid");
 }
 
+mod empty_with_hint {
+    use super::*;
+
+    #[test]
+    fn reports_hint() {
+        let iter = TestIterator::<()>::empty_with_hint((5, Some(10)));
+        assert_eq!(iter.size_hint(), (5, Some(10)));
+    }
+
+    #[test]
+    fn yields_none() {
+        let mut iter = TestIterator::<()>::empty_with_hint((5, Some(10)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (5, Some(10)), "size hint should not change");
+    }
+
+    // `Vec` only reserves based on the size hint of an iterator without `TrustedLen` once the first
+    // element is yielded, so an iterator that claims elements but yields none never allocates.
+    #[test]
+    fn collect_does_not_reserve() {
+        let collected: Vec<u64> = TestIterator::<u64>::empty_with_hint((5, Some(10))).collect();
+        assert!(collected.is_empty(), "no elements should be collected");
+        assert_eq!(collected.capacity(), 0, "no capacity should be reserved");
+    }
+
+    #[test]
+    fn extend_does_not_reserve() {
+        let mut extended: Vec<u64> = Vec::new();
+        extended.extend(TestIterator::<u64>::empty_with_hint((5, Some(10))));
+        assert!(extended.is_empty(), "no elements should be extended");
+        assert_eq!(extended.capacity(), 0, "no capacity should be reserved");
+    }
+}
+
 mod panic {
     use super::*;
 
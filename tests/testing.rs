@@ -0,0 +1,16 @@
+use size_hinter::{SizeHint, testing::sample_hints};
+
+#[test]
+fn sample_hints_are_valid() {
+    for hint in sample_hints() {
+        assert_eq!(SizeHint::try_from(hint.as_hint()), Ok(hint), "{hint:?} should be valid");
+    }
+}
+
+#[test]
+fn sample_hints_include_edge_cases() {
+    let hints: Vec<SizeHint> = sample_hints().collect();
+    for expected in [SizeHint::ZERO, SizeHint::UNIVERSAL, SizeHint::exact(1), SizeHint::unbounded(usize::MAX)] {
+        assert!(hints.contains(&expected), "{expected:?} should be included");
+    }
+}
@@ -0,0 +1,32 @@
+mod macros;
+
+use macros::*;
+
+use std::ops::Range;
+
+use size_hinter::*;
+
+const TEST_ITER: Range<usize> = 1..5;
+
+test_ctor!(valid, TEST_ITER.validated() => hint: (4, Some(4)));
+test_ctor!(len, Validated::new(TEST_ITER) => len: 4);
+
+test_iter!(
+    passthrough,
+    TEST_ITER.validated() => len: 4,
+    next => Some(1), len: 3;
+    next_back => Some(4), len: 2;
+);
+
+panics!(
+    invalid,
+    INVALID_UNIT_ITERATOR.validated().size_hint(),
+    "iterator's size hint should be valid, but was (10, Some(5))"
+);
+panics!(invalid_len, TestIterator::<()>::INVALID.validated().len(), "iterator's size hint should be valid");
+
+#[test]
+fn invalid_not_checked_until_read() {
+    let iter = INVALID_UNIT_ITERATOR.validated();
+    assert!(matches!(iter.into_inner(), InvalidIterator { .. }));
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+mod macros;
+
+use macros::*;
+
+use std::ops::Range;
+
+use size_hinter::*;
+
+const TEST_ITER: Range<usize> = 1..5;
+
+test_ctor!(initial_hint, TEST_ITER.windows_hint(2) => hint: (3, Some(3)));
+test_ctor!(window_too_large, TEST_ITER.windows_hint(5) => hint: (0, Some(0)));
+test_ctor!(zero_window, TEST_ITER.windows_hint(0) => panic: "window size should be non-zero");
+test_ctor!(invalid, INVALID_UNIT_ITERATOR.windows_hint(2).size_hint() => panic: "iterator's size hint should be valid");
+
+test_iter!(
+    pairs,
+    TEST_ITER.windows_hint(2) => hint: (3, Some(3)),
+    next => Some(vec![1, 2]), hint: (2, Some(2));
+    next => Some(vec![2, 3]), hint: (1, Some(1));
+    next => Some(vec![3, 4]), hint: (0, Some(0));
+    next => None::<Vec<usize>>, hint: (0, Some(0));
+);
+
+test_iter!(
+    single,
+    TEST_ITER.windows_hint(1) => hint: (4, Some(4)),
+    next => Some(vec![1]), hint: (3, Some(3));
+);
+
+test_iter!(
+    too_short,
+    TEST_ITER.windows_hint(5) => hint: (0, Some(0)),
+    next => None::<Vec<usize>>, hint: (0, Some(0));
+);
+
+test_iter!(
+    filtered,
+    (1..10).filter(|x| x % 2 == 1).windows_hint(3) => hint: (0, Some(7)),
+    next => Some(vec![1, 3, 5]), hint: (0, Some(4));
+    next => Some(vec![3, 5, 7]), hint: (0, Some(2));
+);
+
+#[test]
+fn window_count_matches_windowed() {
+    for len in 0..6 {
+        for window in 1..6 {
+            let iter = (0..len).windows_hint(window);
+            let expected = SizeHint::exact(len).windowed(window);
+            assert_eq!(iter.size_hint(), expected.as_hint(), "len {len}, window {window}");
+            assert_eq!(iter.count(), expected.lower, "len {len}, window {window}");
+        }
+    }
+}
+
+#[test]
+fn window() {
+    let iter = TEST_ITER.windows_hint(3);
+    assert_eq!(iter.window, 3);
+    assert!(matches!(iter.into_inner(), Range { .. }));
+}